@@ -0,0 +1,46 @@
+// src/anim.rs
+// Tick driven text effects, advanced once per loop tick by App::on_tick
+
+use std::time::Duration;
+
+pub const TICK_RATE: Duration = Duration::from_millis(30);
+pub const BLINK_TICKS: u64 = 16; // cursor flips every ~0.5s
+
+// Reveals a piece of text a few characters per tick. It only counts
+// characters, the text itself stays wherever it lives (header, history...)
+pub struct Typewriter {
+    shown: usize,
+    speed: usize, // chars revealed per tick
+}
+impl Typewriter {
+    pub fn new(speed: usize) -> Self {
+        Self { shown: 0, speed }
+    }
+    pub fn tick(&mut self, total: usize) {
+        self.shown = (self.shown + self.speed).min(total);
+    }
+    // skip to the end, used when animations are switched off
+    pub fn finish(&mut self, total: usize) {
+        self.shown = total;
+    }
+    // back up to `n` chars so what follows them types out again
+    pub fn rewind(&mut self, n: usize) {
+        self.shown = self.shown.min(n);
+    }
+    pub fn shown(&self) -> usize {
+        self.shown
+    }
+}
+
+// First `n` characters of `s`, safe for multi-byte chars like the health bar
+pub fn take_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((i, _)) => &s[..i],
+        None => s,
+    }
+}
+
+// Blinking block cursor, steady when animations are off
+pub fn cursor_visible(tick: u64, animations: bool) -> bool {
+    !animations || (tick / BLINK_TICKS).is_multiple_of(2)
+}
@@ -1,63 +1,223 @@
 // src/app.rs
 
+use anyhow::{bail, ensure};
 use color_eyre::Result;
 use crossterm::event::{ 
-    self, Event, KeyEventKind,
+    self, Event, KeyCode, KeyEventKind,
 };
 use ratatui::DefaultTerminal;
 use ratatui::widgets::TableState;
 use ratatui::layout::Rect;
-use fastrand;
+use serde::{Deserialize, Serialize};
 
 use crate::ui;
+use crate::campaign::{self, Run};
+use crate::cli::{Options, Rules};
+use crate::clock::{Countdown, Stage};
+use crate::content::{Content, Lock};
+use crate::replay::{Playback, Replay};
+use crate::save::SaveGame;
+use crate::solver::{self, Strategy};
+use crate::hint::{Hint, HINT_BUDGET, HINT_COST};
+use crate::profile::{Perk, Profile};
+use crate::stats::{GameResult, Stats};
+use crate::anim::{Typewriter, TICK_RATE};
+use crate::terminal::TerminalScreen;
+use crate::theme::ThemeKind;
+use crate::title::{TitleScreen, DAILY_DIFFICULTY};
 use crate::input::{handle_key, handle_mouse };
 
 use std::collections::VecDeque;
+use std::sync::OnceLock;
+use std::time::Instant;
 
-// Main app loop
-pub fn run(mut terminal: DefaultTerminal) -> Result<()> {
-    let mut app = App::new();
-    let mut side_area = Rect::default(); // updated by ui::render
+// Main app loop, live play or playing back a recorded session
+pub fn run(terminal: DefaultTerminal, content: Content, options: Options, playback: Option<Playback>) -> Result<()> {
+    match playback {
+        Some(playback) => run_replay(terminal, content, &options, playback),
+        None => run_live(terminal, content, &options),
+    }
+}
+
+fn run_live(mut terminal: DefaultTerminal, content: Content, options: &Options) -> Result<()> {
+    // seed everything from one number so the session can be replayed
+    let seed = options.settings.game.seed.unwrap_or_else(|| fastrand::u64(..));
+    fastrand::seed(seed);
+    let mut app = App::new(content, options).map_err(|e| color_eyre::eyre::eyre!("{e:#}"))?;
+    let side_area = Rect::default(); // updated by ui::render
+    let mut last_tick = Instant::now();
+
+    // a broken stats file shouldn't stop the game, start over and say why
+    match Stats::load() {
+        Ok(stats) => app.stats = stats,
+        Err(e) => app.debug.push(format!("stats load error={e:#}")),
+    }
+    match Profile::load() {
+        Ok(profile) => app.profile = profile,
+        Err(e) => app.debug.push(format!("profile load error={e:#}")),
+    }
+    let mut recorder = Replay::new(seed, app.day, options.rules.clone(), app.animations, app.profile.clone());
+    // pick up where the last session quit, old or broken saves are skipped.
+    // asking for a board on the command line leaves the save for next time,
+    // and the board it asked for isn't saved over it
+    let skipped = app.skip_title(&options.rules);
+    let keep_save = skipped && SaveGame::exists();
+    if !skipped {
+        match SaveGame::load() {
+            Ok(Some(save)) => {
+                recorder.resume = Some(save.clone());
+                app.resume(save);
+            }
+            Ok(None) => {}
+            Err(e) => app.debug.push(format!("save load error={e:#}")),
+        }
+        if let Err(e) = SaveGame::clear() {
+            app.debug.push(format!("save clear error={e:#}"));
+        }
+    }
 
     loop {
+        // App only records results, writing them out happens here
+        if app.stats_dirty {
+            if let Err(e) = app.stats.save() {
+                app.debug.push(format!("stats save error={e:#}"));
+            }
+            app.stats_dirty = false;
+        }
+        if app.profile_dirty {
+            if let Err(e) = app.profile.save() {
+                app.debug.push(format!("profile save error={e:#}"));
+            }
+            app.profile_dirty = false;
+        }
+
         // draw UI, passing state in
         terminal.draw(|f| ui::render(f, &mut app))?;
 
+        // wait for input until the next tick is due so animations keep moving
+        let timeout = TICK_RATE.saturating_sub(last_tick.elapsed());
+        if !event::poll(timeout)? {
+            app.on_tick();
+            last_tick = Instant::now();
+            continue;
+        }
 
-        match event::read()? {
-            Event::Key(key) => {
-                // only on key press (skip repeats / releases)
-                if key.kind == KeyEventKind::Press {
-                    if handle_key(key, &mut app) {
-                        // handle_key returns true = quit
-                        return Ok(());
-                    }
+        // rebound keys become the defaults input.rs knows, before recording
+        let Some(event) = options.keymap.translate(event::read()?) else { continue; };
+        recorder.record(app.tick, &event);
+        // handle_event returns true = quit
+        if handle_event(event, &mut app, side_area) { break; }
+    }
+
+    if let Err(e) = recorder.save() {
+        app.debug.push(format!("replay save error={e:#}"));
+    }
+    // only a board that's still in play is worth coming back to
+    if app.screen == Screen::Board && !app.game_over && !keep_save {
+        app.snapshot().save().map_err(|e| color_eyre::eyre::eyre!("{e:#}"))?;
+    }
+    Ok(())
+}
+
+/* drives App with a recording instead of the keyboard. the player's keys
+*  only control playback, and nothing is read from or written to disk so a
+*  replay can't touch real stats or saves
+*/
+fn run_replay(mut terminal: DefaultTerminal, content: Content, options: &Options, mut playback: Playback) -> Result<()> {
+    let mut app = replay_app(content, options, &mut playback).map_err(|e| color_eyre::eyre::eyre!("{e:#}"))?;
+    let side_area = Rect::default();
+    let mut last_tick = Instant::now();
+    let mut stopped = false; // the recording quit the game
+
+    loop {
+        app.banner = Some(playback.label());
+        terminal.draw(|f| ui::render(f, &mut app))?;
+
+        let rate = TICK_RATE.div_f64(playback.speed);
+        let timeout = rate.saturating_sub(last_tick.elapsed());
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press { continue; }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => { break; }
+                    KeyCode::Char('+') | KeyCode::Char('=') => { playback.faster(); }
+                    KeyCode::Char('-') => { playback.slower(); }
+                    KeyCode::Char(' ') => { playback.paused = !playback.paused; }
+                    _ => {}
                 }
             }
-            Event::Mouse(me) => {
-                handle_mouse(me, &mut app, side_area);
-            }
-            _ => {}
+            continue;
+        }
+        last_tick = Instant::now();
+        if playback.paused || stopped { continue; }
+
+        stopped = replay_tick(&mut app, &mut playback, side_area);
+    }
+    Ok(())
+}
+
+/* the App a recording started from: same seed, day, profile, and the board
+*  it resumed or skipped to. `options` already has the replay's settings
+*/
+pub fn replay_app(content: Content, options: &Options, playback: &mut Playback) -> anyhow::Result<App> {
+    fastrand::seed(playback.seed());
+    let mut app = App::new(content, options)?;
+    app.day = playback.day();
+    app.profile = playback.profile();
+    match playback.take_resume() {
+        Some(save) => app.resume(save),
+        None => { app.skip_title(&options.rules); }
+    }
+    Ok(app)
+}
+
+// one tick of playback, true once the recording has quit
+pub fn replay_tick(app: &mut App, playback: &mut Playback, side_area: Rect) -> bool {
+    // events land on the same tick they were recorded on
+    while let Some(event) = playback.due(app.tick) {
+        if handle_event(event, app, side_area) { return true; }
+    }
+    if !playback.finished() { app.on_tick(); }
+    false
+}
+
+// one terminal event into the input handlers, true = quit
+pub fn handle_event(event: Event, app: &mut App, side_area: Rect) -> bool {
+    match event {
+        // only on key press (skip repeats / releases)
+        Event::Key(key) if key.kind == KeyEventKind::Press => handle_key(key, app),
+        Event::Mouse(me) => {
+            handle_mouse(me, app, side_area);
+            false
         }
+        _ => false,
     }
 }
 
 // State Handling
 // ----------------------------------------------------------------------------
 
+// Which full-screen view is up, the panels below only matter on Board
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Screen {
+    Title,
+    Board,
+    Terminal, // unlocked, reached by guessing the password
+    Stats,
+    Profile,  // science skill and perks, from the title menu
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Focus {
     Head,
     Main,
     Side,
-    Help,
 }
 impl Focus {
     pub fn focus_next_vertical(&mut self) {
         *self = match *self {
             Focus::Head => Focus::Main,               // going "down" from header
             Focus::Main | Focus::Side => Focus::Head, // from either body panel -> header
-            Focus::Help => Focus::Help,
         };
     }
 
@@ -66,32 +226,31 @@ impl Focus {
             Focus::Head => Focus::Head,
             Focus::Main => Focus::Side,
             Focus::Side => Focus::Main,
-            Focus::Help => Focus::Help,
         };
     }
 }
 
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Header {
     pub title: String,
     pub status: String,
     pub health_i: u8,
+    pub max_health: u8,
+    #[serde(default)]
+    pub clock: Option<Countdown>, // timed boards only
 }
 impl Header {
-    pub fn new(starting_health: u8) -> Self {
+    // title & status come from the brand / status lines in Content
+    pub fn new(starting_health: u8, title: String, status: String) -> Self {
         Self { 
-            title: Self::new_title(), 
-            status: Self::new_status(1),
+            title, 
+            status,
             health_i: starting_health,
+            max_health: starting_health,
+            clock: None,
         }
     }
-    // Get title & status from assets
-    pub fn new_title() -> String {
-        crate::assets::titles_str(0).to_string()
-    }
-    pub fn new_status(n: u8) -> String {
-        crate::assets::status_str(n).to_string()
-    }
     // Health functions
     pub fn apply_guess(&mut self, guess: &str, password: &str) -> bool {
         if guess == password {
@@ -104,11 +263,15 @@ impl Header {
         }
     }
     pub fn is_out(&self) -> bool {
-        self.health_i <= 0
+        self.health_i == 0
     }
-    /// Get the “█ █ █” bar for current health.
-    pub fn new_health_bar(&self) -> &'static str{
-        crate::assets::health_str(self.health_i)
+    // bracket reward, refills attempts back to the starting amount
+    pub fn replenish(&mut self) {
+        self.health_i = self.max_health;
+    }
+    /// Get the “█ █ █” bar for current health, a block per attempt left.
+    pub fn new_health_bar(&self) -> String {
+        vec!["█"; usize::from(self.health_i)].join(" ")
     }
 }
 
@@ -123,35 +286,130 @@ pub struct TableStructure{
     pub hex_width: u16, // width of columns 0 & 2 need to be bound to the hex string size.
 }
 impl TableStructure {
-    pub fn new() -> Self {
-        Self { rows: 16, columns: 4, column_spacing: 1, hex_width: 1, }
+    pub fn new(column_spacing: u16) -> Self {
+        Self { rows: 16, columns: 4, column_spacing, hex_width: 1, }
+    }
+
+    // widest address on the board plus the prefix ui draws before it
+    pub fn fit_hex(&mut self, hex_list: &[String], prefix: &str) {
+        let widest = hex_list.iter().map(|h| h.chars().count()).max().unwrap_or(0);
+        self.hex_width = (widest + prefix.chars().count()) as u16;
+    }
+
+    // hex address columns, then play columns of one cell each
+    pub fn widths(&self) -> Vec<u16> {
+        (0..self.columns)
+            .map(|col| match TableModel::cell_index(0, col) {
+                None => self.hex_width,
+                Some(_) => CELL_LEN as u16,
+            })
+            .collect()
+    }
+
+    // the whole table without borders, every column and the gaps between
+    pub fn width(&self) -> u16 {
+        let gaps = self.column_spacing * (self.columns as u16).saturating_sub(1);
+        self.widths().iter().sum::<u16>() + gaps
     }
 }
 
+// Board geometry, 32 cells of 8 chars split into two 16 cell panel halves
+pub const CELL_LEN: usize = 8;
+pub const TOTAL_CELLS: usize = 32;
+pub const BOARD_CHARS: usize = CELL_LEN * TOTAL_CELLS;
+pub const HALF_CHARS: usize = BOARD_CHARS / 2;
+
+// Junk distribution tuning for TableModel::generate_junk
+pub const MIN_JUNK_GAP: usize = 2;       // fewest junk chars between two words
+pub const JUNK_VARIANCE_PCT: usize = 50; // how far a gap may drift from the even share
+
+// Word list boards are dealt from, read once per process
+static WORDS: OnceLock<Vec<String>> = OnceLock::new();
+
+// Word sets tried per board when the lock asks for a solvable one
+pub const MAX_DEALS: usize = 50;
+
+// Roughly the Fallout terminal lock levels, the board each one builds is
+// described by its content::Lock
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    #[default]
+    Novice,
+    Advanced,
+    Expert,
+    Master,
+}
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Novice,
+        Difficulty::Advanced,
+        Difficulty::Expert,
+        Difficulty::Master,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Novice   => "Novice",
+            Difficulty::Advanced => "Advanced",
+            Difficulty::Expert   => "Expert",
+            Difficulty::Master   => "Master",
+        }
+    }
+    // case-insensitive, for the command line
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        Difficulty::ALL.into_iter().find(|d| d.name().eq_ignore_ascii_case(name))
+    }
+}
+
+// Saved as-is by save::SaveGame, play_space is rebuilt from junk_word_list
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TableModel {
     pub hex_list: Vec<String>,
     pub word_list: Vec<String>,
     pub junk_word_list: Vec<String>,
+    #[serde(skip)]
     pub play_space: Vec<String>,
     pub password: String,
-    pub state: TableState,
+
+    pub difficulty: Difficulty,
+    pub removed_duds: Vec<String>, // duds blanked out by bracket pairs
+    pub brackets_used: usize,
+    pub guesses: Vec<(String, usize)>, // (word, likeness) in the order guessed
+
+    pub hints_used: usize,
+    pub revealed: Vec<usize>,  // password letter positions given away by hints
+    pub show_eliminated: bool, // dim words the guesses so far have ruled out
+    #[serde(skip)]
+    pub unsolvable: bool, // a solvable lock ran out of deals, see deal_words
 }
 impl TableModel {
     /// High-level constructor: this is what you call from `App::new()`.
-    pub fn new() -> Self {
+    /// Errors if the lock asks for a board the word list can't fill.
+    pub fn new(difficulty: Difficulty, lock: &Lock) -> anyhow::Result<Self> {
         let hex_list = Self::build_hex_list();
-        let word_list = Self::new_word_list(8);
-        let password = Self::new_password(&word_list);
-        let junk_word_list = Self::generate_junk(&word_list);
-        let play_space = Self::build_play_space(&junk_word_list);
-        Self { 
+        let (word_list, unsolvable) = Self::deal_words(lock)?;
+        let password = Self::new_password(&word_list)?;
+        let mut junk_word_list = Self::generate_junk(&word_list)?;
+        Self::plant_brackets(&mut junk_word_list, lock.bracket_pairs)?;
+        let play_space = Self::build_play_space(&junk_word_list)?;
+        Ok(Self { 
             hex_list,
             word_list,
             password, 
             junk_word_list,
             play_space,
-            state: TableState::new(),
-        }
+
+            difficulty,
+            removed_duds: Vec::new(),
+            brackets_used: 0,
+            guesses: Vec::new(),
+
+            hints_used: 0,
+            revealed: Vec::new(),
+            show_eliminated: false,
+            unsolvable,
+        })
     }
 
     pub fn build_hex_list() -> Vec<String> {
@@ -165,84 +423,426 @@ impl TableModel {
     /* Chain for generating a word list, takes tokens from assets/tokens.txt 
     *  generates word_list -> gets password from list
     */
-    fn load_words() -> Vec<String> {
-        let contents = std::fs::read_to_string("assets/tokens.txt")
-            .expect("failed to read tokens.txt");
-
-        contents.lines().map(|s| s.to_string()).collect()
+    // main installs the validated list, the default file is the fallback
+    pub fn use_words(words: Vec<String>) {
+        let _ = WORDS.set(words);
+    }
+    fn load_words() -> &'static [String] {
+        WORDS.get_or_init(|| {
+            crate::content::load_words(std::path::Path::new(crate::content::WORDS_PATH))
+                .expect("failed to read tokens.txt")
+        })
+    }
+    /* redeal until the solver can always crack the set within the lock's
+    *  attempts, when the lock asks for it. capped so a lock that can never
+    *  be solvable (1 attempt, say) still gets a board, flagged unsolvable
+    */
+    fn deal_words(lock: &Lock) -> anyhow::Result<(Vec<String>, bool)> {
+        let mut word_list = Self::new_word_list(lock.word_count, lock.word_len)?;
+        if !lock.solvable { return Ok((word_list, false)); }
+        for _ in 1..MAX_DEALS {
+            if solver::solvable(&word_list, &word_list, lock.attempts) { return Ok((word_list, false)); }
+            word_list = Self::new_word_list(lock.word_count, lock.word_len)?;
+        }
+        let unsolvable = !solver::solvable(&word_list, &word_list, lock.attempts);
+        Ok((word_list, unsolvable))
     }
-    // n = number of words in play area
-    pub fn new_word_list(n: usize) -> Vec<String> {
-        let mut tokens = Self::load_words();
+    // n = number of words in play area, len = chars per word. repeats in the
+    // list are dropped so no word lands on the board twice
+    pub fn new_word_list(n: usize, len: usize) -> anyhow::Result<Vec<String>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut tokens: Vec<String> = Self::load_words()
+            .iter()
+            .filter(|w| w.chars().count() == len && seen.insert(w.as_str()))
+            .cloned()
+            .collect();
+        ensure!(tokens.len() >= n, "need {n} words of {len} letters, the list has {}", tokens.len());
         fastrand::shuffle(&mut tokens);
-        let word_list: Vec<String> = tokens.into_iter().take(n).collect();
-        return word_list;
+        Ok(tokens.into_iter().take(n).collect())
     }
     // Find out what the &[String] does...
-    pub fn new_password(word_list: &[String]) -> String {
+    pub fn new_password(word_list: &[String]) -> anyhow::Result<String> {
+        ensure!(!word_list.is_empty(), "no words to pick a password from");
         let i = fastrand::usize(..word_list.len());
-        let password = word_list[i].clone();
-        return password;
+        Ok(word_list[i].clone())
     }
 
-    /* balls n bins implementation generates junk around each word and returns a 
-    *  Vec<String> that looks like (junk, word, junk, word...
+    /* even junk distribution, generates junk around each word and returns a
+    *  Vec<String> that looks like (junk, word, junk, word ... junk).
+    *
+    *  words are split between the two panel halves so none straddles the
+    *  middle, then each half's junk is dealt evenly into its gaps and jittered
+    *  within JUNK_VARIANCE_PCT of the even share. every gap is at least
+    *  MIN_JUNK_GAP wide so no two words are glued together, and the output
+    *  always adds up to exactly BOARD_CHARS. errors if the words can't fit.
     */
-    pub fn generate_junk(word_list: &[String]) -> Vec<String> {
-        let total_chars: usize = word_list.iter().map(|s| s.chars().count()).sum();
-        let total_junk: usize = 256usize.saturating_sub(total_chars);
+    pub fn generate_junk(word_list: &[String]) -> anyhow::Result<Vec<String>> {
+        let split = Self::split_halves(word_list)?;
+        let (left, right) = word_list.split_at(split);
 
-        let (mut content, empty_indices): (Vec<String>, Vec<usize>) = word_list
-            .into_iter()
-            .flat_map(|w| [String::new(), w.clone()])  // build "", w, "", w, ...
-            .enumerate()                               // attach final index: (idx, item)
-            .fold((Vec::new(), Vec::new()), |(mut out, mut idxs), (i, s)| {
-                if s.is_empty() {                      // the inserted ""
-                    idxs.push(i);
-                }
-                out.push(s);
-                (out, idxs)
-            });
+        // trailing junk of the left half and leading junk of the right half
+        // sit back to back, so they are merged into a single gap
+        let mut gaps = Self::distribute_junk(left);
+        let right_gaps = Self::distribute_junk(right);
+        let seam = gaps.pop().unwrap_or(0) + right_gaps[0];
+        gaps.push(seam);
+        gaps.extend_from_slice(&right_gaps[1..]);
 
-        assert!(!empty_indices.is_empty(), "nowhere to put junk (no bins)");
-        if total_junk == 0 { return content; }
-
-        let fr_max_1: usize = empty_indices.len();
         let junkpool: Vec<char> =
             "!@#$%^&*(){}[]<>+-_=|\\/:;'\"`,.?~".chars().collect();
-        let fr_max_2: usize = junkpool.len();
+        let junk = |len: usize| -> String {
+            (0..len).map(|_| junkpool[fastrand::usize(..junkpool.len())]).collect()
+        };
 
-        // get a random index empty_indices[i] to place a random char junkpool[j] into
-        // content[empty_indices[i]]
-        for _ in 0..total_junk { 
-            let i = fastrand::usize(..fr_max_1);
-            let j = fastrand::usize(..fr_max_2);
+        let mut content: Vec<String> = Vec::with_capacity(word_list.len() * 2 + 1);
+        for (gap, word) in gaps.iter().zip(word_list) {
+            content.push(junk(*gap));
+            content.push(word.clone());
+        }
+        content.push(junk(gaps[word_list.len()]));
 
-            let junk_char = junkpool[j];
+        let total: usize = content.iter().map(|s| s.chars().count()).sum();
+        ensure!(total == BOARD_CHARS, "junk + words came to {total} chars, the board holds {BOARD_CHARS}");
+        Ok(content)
+    }
 
-            content[empty_indices[i]].push(junk_char);
-        };
-        return content
+    // a half of k words has k + 1 gaps each needing at least MIN_JUNK_GAP
+    fn fits_in_half(words: &[String]) -> bool {
+        let word_chars: usize = words.iter().map(|s| s.chars().count()).sum();
+        word_chars + (words.len() + 1) * MIN_JUNK_GAP <= HALF_CHARS
+    }
+
+    // index to split word_list at so both halves fit and carry a similar
+    // number of characters
+    fn split_halves(word_list: &[String]) -> anyhow::Result<usize> {
+        let total: usize = word_list.iter().map(|s| s.chars().count()).sum();
+        let mut left_chars = 0usize;
+        let mut best: Option<(usize, usize)> = None; // (split, imbalance)
+
+        for split in 0..=word_list.len() {
+            if split > 0 {
+                left_chars += word_list[split - 1].chars().count();
+            }
+            let (left, right) = word_list.split_at(split);
+            if !Self::fits_in_half(left) || !Self::fits_in_half(right) {
+                continue;
+            }
+            let imbalance = left_chars.abs_diff(total - left_chars);
+            if best.is_none_or(|(_, b)| imbalance < b) {
+                best = Some((split, imbalance));
+            }
+        }
+
+        match best {
+            Some((split, _)) => Ok(split),
+            None => bail!(
+                "{} words ({total} chars) don't fit in the board with {MIN_JUNK_GAP} junk chars between them",
+                word_list.len(),
+            ),
+        }
+    }
+
+    /* deals one half's junk into len + 1 gaps. each gap starts at the even share
+    *  (remainder handed to random gaps) then random pairs of gaps trade chars
+    *  while both stay inside [share - variance, share + 1 + variance]. a trade
+    *  that would end two words on the same line is skipped, so every word
+    *  keeps a cell of its own (see word_at_cell)
+    */
+    fn distribute_junk(words: &[String]) -> Vec<usize> {
+        let bins = words.len() + 1;
+        let word_chars: usize = words.iter().map(|s| s.chars().count()).sum();
+        let total_junk = HALF_CHARS - word_chars;
+
+        let share = total_junk / bins;
+        let mut gaps = vec![share; bins];
+
+        let mut order: Vec<usize> = (0..bins).collect();
+        fastrand::shuffle(&mut order);
+        for &i in order.iter().take(total_junk % bins) {
+            gaps[i] += 1;
+        }
+
+        let variance = share * JUNK_VARIANCE_PCT / 100;
+        let lo = share.saturating_sub(variance).max(MIN_JUNK_GAP);
+        let hi = share + 1 + variance;
+
+        let mut shared = Self::shared_ends(words, &gaps);
+        for _ in 0..bins * 2 {
+            let from = fastrand::usize(..bins);
+            let to = fastrand::usize(..bins);
+            let amount = fastrand::usize(..=variance);
+            if from != to && gaps[from] >= lo + amount && gaps[to] + amount <= hi {
+                gaps[from] -= amount;
+                gaps[to] += amount;
+                let after = Self::shared_ends(words, &gaps);
+                if after > shared {
+                    gaps[from] += amount;
+                    gaps[to] -= amount;
+                } else {
+                    shared = after;
+                }
+            }
+        }
+
+        gaps
+    }
+
+    // words in a half that end on the same line as the word before them.
+    // halves are whole lines, so offsets can start from the half's own start
+    fn shared_ends(words: &[String], gaps: &[usize]) -> usize {
+        let mut offset = 0usize;
+        let mut last_line = None;
+        let mut shared = 0usize;
+        for (word, gap) in words.iter().zip(gaps) {
+            offset += gap + word.chars().count();
+            let line = (offset - 1) / CELL_LEN;
+            if last_line == Some(line) { shared += 1; }
+            last_line = Some(line);
+        }
+        shared
+    }
+
+    /* plants `pairs` clean bracket pairs into the junk, at most one per cell
+    *  (a cell is one line of the board). a pair only ever sits inside a single
+    *  junk run so it can't enclose letters, and each one is checked with
+    *  bracket_pairs afterwards. returns the cells that got a pair.
+    */
+    pub fn plant_brackets(junk_word_list: &mut [String], pairs: usize) -> anyhow::Result<Vec<usize>> {
+        // (segment idx, offset into segment, run length) for every piece of a
+        // junk segment that fits inside one cell
+        let mut runs: Vec<(usize, usize, usize)> = Vec::new();
+        let mut offset = 0usize;
+        for (seg, s) in junk_word_list.iter().enumerate() {
+            let len = s.chars().count();
+            if seg % 2 == 0 {
+                let mut start = 0usize;
+                while start < len {
+                    let cell_end = ((offset + start) / CELL_LEN + 1) * CELL_LEN;
+                    let end = (cell_end - offset).min(len);
+                    if end - start >= 2 {
+                        runs.push((seg, start, end - start));
+                    }
+                    start = end;
+                }
+            }
+            offset += len;
+        }
+        fastrand::shuffle(&mut runs);
+
+        let opens = ['(', '[', '{', '<'];
+        let mut planted: Vec<usize> = Vec::with_capacity(pairs);
+
+        for (seg, start, len) in runs {
+            if planted.len() == pairs { break; }
+
+            let seg_offset: usize = junk_word_list[..seg].iter().map(|s| s.chars().count()).sum();
+            let cell = (seg_offset + start) / CELL_LEN;
+            if planted.contains(&cell) { continue; }
+
+            let open = opens[fastrand::usize(..opens.len())];
+            let Some(close) = crate::assets::acceptable_combination(open) else {
+                bail!("no closing bracket for {open:?}");
+            };
+
+            let a = start + fastrand::usize(..len - 1);
+            let b = fastrand::usize(a + 1..start + len);
+
+            let mut chars: Vec<char> = junk_word_list[seg].chars().collect();
+            chars[a] = open;
+            chars[b] = close;
+            junk_word_list[seg] = chars.into_iter().collect();
+
+            // verify against the same parser the game uses to find pairs
+            let board: Vec<char> = junk_word_list.iter().flat_map(|s| s.chars()).collect();
+            let cell_str: String = board[cell * CELL_LEN..(cell + 1) * CELL_LEN].iter().collect();
+            let local = seg_offset + a - cell * CELL_LEN;
+            ensure!(
+                Self::bracket_pairs(&cell_str).iter().any(|&(o, _)| o == local),
+                "planted bracket pair doesn't parse: {cell_str:?}"
+            );
+            planted.push(cell);
+        }
+
+        Ok(planted)
+    }
+
+    /* finds usable bracket pairs in one cell as (open, close) char indices. an
+    *  opening bracket pairs with the first matching closer after it on the same
+    *  line, as long as no letter sits in between
+    */
+    pub fn bracket_pairs(cell: &str) -> Vec<(usize, usize)> {
+        let chars: Vec<char> = cell.chars().collect();
+        let mut pairs = Vec::new();
+
+        for (i, &c) in chars.iter().enumerate() {
+            let Some(close) = crate::assets::acceptable_combination(c) else { continue; };
+            for (j, &d) in chars.iter().enumerate().skip(i + 1) {
+                if d.is_alphabetic() { break; }
+                if d == close {
+                    pairs.push((i, j));
+                    break;
+                }
+            }
+        }
+        pairs
+    }
+
+    // offset range of every word on the board, same order as word_list
+    pub fn word_spans(&self) -> Vec<std::ops::Range<usize>> {
+        let mut spans = Vec::with_capacity(self.word_list.len());
+        let mut offset = 0usize;
+        for (i, s) in self.junk_word_list.iter().enumerate() {
+            let len = s.chars().count();
+            if i % 2 == 1 {
+                spans.push(offset..offset + len);
+            }
+            offset += len;
+        }
+        spans
+    }
+
+    /* the cell each word owns, same order as word_list. in board order a
+    *  word takes the first cell it reaches that the word before it didn't,
+    *  so a short word behind another's tail still gets its cell. None only
+    *  if the board was dealt without generate_junk's one end per line
+    */
+    pub fn owned_cells(&self) -> Vec<Option<usize>> {
+        let mut next_free = 0usize;
+        self.word_spans()
+            .into_iter()
+            .map(|span| {
+                let cell = (span.start / CELL_LEN).max(next_free);
+                if cell * CELL_LEN >= span.end { return None; }
+                next_free = cell + 1;
+                Some(cell)
+            })
+            .collect()
+    }
+
+    // the word still on the board that owns `cell`, or else the first one
+    // that overlaps it
+    pub fn word_at_cell(&self, cell: usize) -> Option<String> {
+        let cell_range = cell * CELL_LEN..(cell + 1) * CELL_LEN;
+        let live: Vec<_> = self.word_spans()
+            .into_iter()
+            .zip(self.owned_cells())
+            .zip(&self.word_list)
+            .filter(|(_, w)| !self.removed_duds.contains(w))
+            .collect();
+        live.iter()
+            .find(|((_, owned), _)| *owned == Some(cell))
+            .or_else(|| live.iter().find(|((span, _), _)| span.start < cell_range.end && cell_range.start < span.end))
+            .map(|(_, w)| (*w).clone())
+    }
+
+    // words not yet blanked out by a bracket pair
+    pub fn remaining_words(&self) -> Vec<String> {
+        self.word_list
+            .iter()
+            .filter(|w| !self.removed_duds.contains(w))
+            .cloned()
+            .collect()
+    }
+
+    // remaining words that haven't been guessed yet
+    pub fn guess_pool(&self) -> Vec<String> {
+        self.remaining_words()
+            .into_iter()
+            .filter(|w| !self.guesses.iter().any(|(g, _)| g == w))
+            .collect()
+    }
+
+    // remaining words the likeness results so far rule out
+    pub fn eliminated_words(&self) -> Vec<String> {
+        let remaining = self.remaining_words();
+        let candidates = solver::consistent(&remaining, &self.guesses);
+        remaining.into_iter().filter(|w| !candidates.contains(w)).collect()
+    }
+
+    // letters that match in the same position
+    pub fn likeness(guess: &str, password: &str) -> usize {
+        guess.chars().zip(password.chars()).filter(|(a, b)| a == b).count()
+    }
+
+    // maps a (row, column) in the alternating table to its play_space cell,
+    // None for the hex columns
+    pub fn cell_index(row: usize, col: usize) -> Option<usize> {
+        match col {
+            1 => Some(row),
+            3 => Some(row + TOTAL_CELLS / 2),
+            _ => None,
+        }
+    }
+
+    // dots out the first usable bracket pair in `cell` so it can't be used
+    // twice, false if the cell has none
+    pub fn consume_brackets(&mut self, cell: usize) -> bool {
+        let Some(cell_str) = self.play_space.get(cell) else { return false; };
+        let Some(&(open, close)) = Self::bracket_pairs(cell_str).first() else { return false; };
+
+        let start = cell * CELL_LEN;
+        self.set_board_char(start + open, '.');
+        self.set_board_char(start + close, '.');
+        self.brackets_used += 1;
+        self.rebuild_play_space();
+        true
+    }
+
+    // blanks out a random dud still on the board, returns the removed word
+    pub fn remove_dud(&mut self) -> Option<String> {
+        let duds: Vec<usize> = (0..self.word_list.len())
+            .filter(|&i| self.word_list[i] != self.password)
+            .filter(|&i| !self.removed_duds.contains(&self.word_list[i]))
+            .collect();
+        if duds.is_empty() { return None; }
+
+        let i = duds[fastrand::usize(..duds.len())];
+        let dud = self.word_list[i].clone();
+        // words sit at the odd indices of junk_word_list
+        self.junk_word_list[i * 2 + 1] = ".".repeat(dud.chars().count());
+        self.removed_duds.push(dud.clone());
+        self.rebuild_play_space();
+        Some(dud)
+    }
+
+    // edits in place keep every segment's length so the board still adds up,
+    // if it somehow doesn't the last good play_space stays on screen
+    fn rebuild_play_space(&mut self) {
+        if let Ok(play_space) = Self::build_play_space(&self.junk_word_list) {
+            self.play_space = play_space;
+        }
+    }
+
+    // overwrite a single char of the board by its flat offset
+    fn set_board_char(&mut self, pos: usize, c: char) {
+        let mut offset = 0usize;
+        for s in self.junk_word_list.iter_mut() {
+            let len = s.chars().count();
+            if pos < offset + len {
+                let mut chars: Vec<char> = s.chars().collect();
+                chars[pos - offset] = c;
+                *s = chars.into_iter().collect();
+                return;
+            }
+            offset += len;
+        }
     }
 
     /* helper function turns output of generate_junk to a string then back into a
     *  vec where each cell is an equal number of characters.
     */
-    pub fn build_play_space(junk_word_list: &[String]) -> Vec<String> {
-        let cell_len = 8;
-        let total_cells = 32;
-
+    pub fn build_play_space(junk_word_list: &[String]) -> anyhow::Result<Vec<String>> {
         // Flatten into Vec<char> so we can chunk by *characters* safely
         let chars: Vec<char> = junk_word_list.iter().flat_map(|s| s.chars()).collect();
 
-        assert_eq!(chars.len(), cell_len * total_cells, "expected 256 chars total");
+        ensure!(chars.len() == BOARD_CHARS, "expected {BOARD_CHARS} chars total, got {}", chars.len());
 
         // Chunk into 8-char cells
-        chars
-            .chunks(cell_len)
-            .take(total_cells)
+        Ok(chars
+            .chunks(CELL_LEN)
+            .take(TOTAL_CELLS)
             .map(|chunk| chunk.iter().collect::<String>())
-            .collect()
+            .collect())
     }
 
     /* builds a Vec<Vec<String>> that looks likes this
@@ -254,11 +854,11 @@ impl TableModel {
         rows: usize,
         hex_list: &[String],
         play_space: &[String],
-    ) -> Vec<Vec<String>> {
-        assert!(columns == 4,           "there should be 4 columns");
-        assert!(rows == 16,             "there should be 16 rows for play_space");
-        assert!(play_space.len() == 32, "junk+word_list should be split into 32 cells");
-        assert!(hex_list.len() == 32,   "hex_list should have 32 elements");
+    ) -> anyhow::Result<Vec<Vec<String>>> {
+        ensure!(columns == 4,           "there should be 4 columns, got {columns}");
+        ensure!(rows == 16,             "there should be 16 rows for play_space, got {rows}");
+        ensure!(play_space.len() == 32, "junk+word_list should be split into 32 cells, got {}", play_space.len());
+        ensure!(hex_list.len() == 32,   "hex_list should have 32 elements, got {}", hex_list.len());
 
         let mut hex_idx = 0usize; // global iterator for hex_list
 
@@ -284,7 +884,7 @@ impl TableModel {
             result.push(inner);
         }
 
-        result
+        Ok(result)
     }
 }
 
@@ -311,6 +911,12 @@ impl DebugLog {
 
 
 pub struct App {
+    pub content: Content,
+    pub screen: Screen,
+    pub title: TitleScreen,
+    pub daily: bool,
+    pub terminal: TerminalScreen,
+
     pub focus: Focus,
     pub header: Header,
 
@@ -324,21 +930,58 @@ pub struct App {
     pub input: String,
 
     pub show_help: bool,
+    pub show_hints: bool,
+    pub show_tracker: bool, // Side panel lists candidates instead of history
     pub game_over: bool,
 
     pub debug: DebugLog,
     pub show_debug: bool,
 
+    pub day: u64,          // daily board number, see title::daily_seed
+    pub banner: Option<String>, // one line along the bottom, e.g. replay controls
+
+    pub stats: Stats,
+    pub stats_dirty: bool, // run() saves stats when set
+    pub profile: Profile,
+    pub profile_dirty: bool, // run() saves the profile when set
+    pub profile_note: Option<String>, // why the last perk pick was refused
+    pub started: Instant,  // when the current board was dealt
+    pub time_limit: Option<u64>, // timed mode, seconds on the clock for every board
+    pub run: Option<Run>,        // terminal run in progress
+
+    pub theme: ThemeKind,
+    pub hex_prefix: String, // drawn before each address, config display.hex_prefix
+
+    pub tick: u64,
+    pub animations: bool, // off = everything shows up instantly, no blinking
+    pub header_fx: Typewriter,
+    pub history_fx: Typewriter,
 }
 
 impl App {
-    pub fn new() -> Self {
-        let main = TableModel::new();
-        let ts = TableStructure::new();
-        let table_contents = TableModel::build_alternating_lists(ts.columns, ts.rows, &main.hex_list, &main.play_space);
+    pub fn new(content: Content, options: &Options) -> anyhow::Result<Self> {
+        let difficulty = options.rules.difficulty.unwrap_or_default();
+        let lock = content.lock(difficulty);
+        let main = TableModel::new(difficulty, lock)?;
+        let mut ts = TableStructure::new(options.settings.display.column_spacing);
+        ts.fit_hex(&main.hex_list, &options.settings.display.hex_prefix);
+        let table_contents = TableModel::build_alternating_lists(ts.columns, ts.rows, &main.hex_list, &main.play_space)?;
         let state = TableState::default().with_selected(Some(0));
-        let header = Header::new(4);
-        Self {
+        let mut title = TitleScreen::new(&content);
+        // animations off from the start, the menu is up straight away
+        if !options.settings.display.animations { title.skip_boot(); }
+        let header = Header::new(
+            lock.attempts,
+            content.brand(title.brand).title.clone(),
+            content.status.password.clone(),
+        );
+        Ok(Self {
+            screen: Screen::Title,
+            title,
+            daily: false,
+            terminal: TerminalScreen::new(Vec::new()),
+            content,
+
             focus: Focus::Main,
             header,
 
@@ -352,12 +995,272 @@ impl App {
             input: String::new(),
 
             show_help: false,
+            show_hints: false,
+            show_tracker: false,
             game_over: false,
 
             debug: DebugLog::new(200),
             show_debug: false,
+
+            day: crate::title::daily_seed(),
+            banner: None,
+
+            stats: Stats::default(),
+            stats_dirty: false,
+            profile: Profile::default(),
+            profile_dirty: false,
+            profile_note: None,
+            started: Instant::now(),
+            time_limit: options.rules.time_limit,
+            run: None,
+
+            theme: options.settings.display.theme,
+            hex_prefix: options.settings.display.hex_prefix.clone(),
+
+            tick: 0,
+            animations: options.settings.display.animations,
+            header_fx: Typewriter::new(1),
+            history_fx: Typewriter::new(2),
+        })
+    }
+
+    // leave the title screen for a fresh board picked from the menu
+    // --difficulty / --daily go straight to a board, true if one started
+    pub fn skip_title(&mut self, rules: &Rules) -> bool {
+        if rules.daily {
+            self.start_board(DAILY_DIFFICULTY, true)
+        } else if rules.run {
+            self.start_run()
+        } else if let Some(difficulty) = rules.difficulty {
+            self.start_board(difficulty, false)
+        } else {
+            false
+        }
+    }
+
+    // false if the board couldn't be dealt, the screen stays where it was
+    pub fn start_board(&mut self, difficulty: Difficulty, daily: bool) -> bool {
+        let lock = self.content.lock(difficulty).clone();
+        // the daily board is dealt from the day number, then the session's
+        // own sequence picks up where it left off
+        let session = fastrand::get_seed();
+        if daily { fastrand::seed(self.day); }
+        let dealt = self.deal(difficulty, &lock, daily);
+        if daily { fastrand::seed(session); }
+
+        if let Err(e) = dealt {
+            self.debug.push(format!("start_board difficulty={} error={e:#}", difficulty.name()));
+            return false;
+        }
+        self.run = None;
+        self.debug.push(format!("start_board difficulty={} daily={}", difficulty.name(), self.daily));
+        true
+    }
+
+    // title menu / --run, a new terminal run from its first terminal
+    pub fn start_run(&mut self) -> bool {
+        let run = Run::new(&self.content);
+        let difficulty = campaign::difficulty_for(&self.content, &run.lock);
+        if let Err(e) = self.deal(difficulty, &run.lock, false) {
+            self.debug.push(format!("start_run error={e:#}"));
+            return false;
+        }
+        self.run = Some(run);
+        self.debug.push("start_run".to_string());
+        true
+    }
+
+    /* a run's terminal was just cracked (finish_game scored it), deal the
+    *  next one. if the harder lock can't be dealt, a short word list say, the
+    *  same lock comes round again. the new board's history opens with how
+    *  the last one went, if nothing deals the old board's history goes on
+    */
+    fn next_terminal(&mut self) {
+        let Some(run) = &self.run else { return; };
+        let attempts = Run::carried_attempts(self.header.health_i);
+        let points = run.cracked.last().map_or(0, |c| c.points);
+        let cracked = run.cracked.len();
+
+        let mut dealt = None;
+        for mut lock in [run.harder(), run.lock.clone()] {
+            lock.attempts = attempts;
+            let difficulty = campaign::difficulty_for(&self.content, &lock);
+            match self.deal(difficulty, &lock, false) {
+                Ok(()) => { dealt = Some(lock); break; }
+                Err(e) => self.debug.push(format!("next_terminal error={e:#}")),
+            }
+        }
+        let cracked_line = format!(">Terminal {cracked} cracked, +{points}.");
+        match &dealt {
+            Some(_) => {
+                // deal left only the new board's own lines, a perk's say
+                let mut items = vec![cracked_line, format!(">Connecting to terminal {}...", cracked + 1)];
+                items.append(&mut self.items);
+                self.items = items;
+            }
+            None => {
+                self.items.push(cracked_line);
+                self.items.push(">No more terminals in reach.".to_string());
+                self.items.push(">Run over.".to_string());
+            }
+        }
+        if !self.animations {
+            let history_total = self.history_chars();
+            self.history_fx.finish(history_total);
+        }
+
+        let Some(lock) = dealt else {
+            // nothing left to deal, the run ends on the terminal it cracked
+            // and the summary goes up as it would after a lockout
+            self.set_status(self.content.status.granted.clone());
+            self.game_over = true;
+            if let Some(run) = &self.run { self.stats.record_run(run); }
+            self.stats_dirty = true;
+            return;
+        };
+        self.debug.push(format!(
+            "next_terminal terminal={} word_len={} word_count={} attempts={attempts}",
+            cracked + 1, lock.word_len, lock.word_count,
+        ));
+        if let Some(run) = &mut self.run { run.lock = lock; }
+    }
+
+    /* deals `lock` as the player's profile sees it and puts it on screen as a
+    *  fresh board, nothing changes on error. daily boards are dealt as-is
+    */
+    fn deal(&mut self, difficulty: Difficulty, lock: &Lock, daily: bool) -> anyhow::Result<()> {
+        let lock = if daily { lock.clone() } else { self.profile.apply(difficulty, lock) };
+        let mut board = TableModel::new(difficulty, &lock)?;
+        // deal_words gives up after MAX_DEALS, a lock like that plays anyway
+        if board.unsolvable {
+            self.debug.push(format!(
+                "deal unsolvable word_len={} word_count={} attempts={} after {MAX_DEALS} deals",
+                lock.word_len, lock.word_count, lock.attempts,
+            ));
+        }
+        let removed = if !daily && self.profile.has(Perk::AutoRemoveDud) { board.remove_dud() } else { None };
+        self.main = board;
+        self.header = Header::new(
+            lock.attempts,
+            self.content.brand(self.title.brand).title.clone(),
+            self.content.status.password.clone(),
+        );
+        self.header.clock = self.time_limit.map(Countdown::new);
+        // a short limit starts out low
+        self.refresh_status();
+        self.daily = daily;
+        self.items.clear();
+        if removed.is_some() { self.items.push(">Perk: dud removed.".to_string()); }
+        self.input.clear();
+        self.game_over = false;
+        self.show_hints = false;
+        self.show_tracker = false;
+        self.state.select(Some(0));
+        self.col_state = 0;
+        self.refresh_table();
+        self.started = Instant::now();
+
+        self.header_fx = Typewriter::new(1);
+        self.history_fx = Typewriter::new(2);
+        if !self.animations {
+            let (header_total, history_total) = (self.header_chars(), self.history_chars());
+            self.header_fx.finish(header_total);
+            self.history_fx.finish(history_total);
+        }
+
+        self.screen = Screen::Board;
+        Ok(())
+    }
+
+    // advance animations one step, called by run() every TICK_RATE
+    pub fn on_tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        self.tick_clock();
+        if !self.animations { return; }
+
+        if self.screen == Screen::Title {
+            let total = self.title.boot_chars();
+            self.title.boot_fx.tick(total);
+            return;
+        }
+
+        let header_total = self.header_chars();
+        self.header_fx.tick(header_total);
+        let history_total = self.history_chars();
+        // boot text first, history starts typing once the header is done
+        if self.header_fx.shown() == header_total {
+            self.history_fx.tick(history_total);
+        }
+    }
+
+    /* timed boards lose a tick's worth of time on every tick, whatever the
+    *  animations are doing. the status line escalates as the clock runs low
+    *  and the board locks out when it runs out
+    */
+    fn tick_clock(&mut self) {
+        if self.screen != Screen::Board || self.game_over { return; }
+        let Some(clock) = &mut self.header.clock else { return; };
+        let Some(stage) = clock.tick(TICK_RATE.as_millis() as u64) else { return; };
+        self.debug.push(format!("clock stage={stage:?} tick={}", self.tick));
+        if stage == Stage::Expired {
+            self.items.push(">Session timed out.".to_string());
+            self.game_over = true;
+            self.finish_game(false);
+        }
+        self.refresh_status();
+    }
+
+    /* the status line for where the board stands, worked out from both the
+    *  attempts and the clock every time either changes so neither hides the
+    *  other: out of time or attempts, then critical time, the last attempt,
+    *  low time, and the plain password prompt
+    */
+    fn refresh_status(&mut self) {
+        let lines = &self.content.status;
+        let stage = self.header.clock.map_or(Stage::Plenty, |clock| clock.stage());
+        let status = if stage == Stage::Expired {
+            &lines.timeout
+        } else if self.header.is_out() {
+            &lines.locked
+        } else if stage == Stage::Critical {
+            &lines.time_critical
+        } else if self.header.health_i == 1 {
+            &lines.warning
+        } else if stage == Stage::Low {
+            &lines.time_low
+        } else {
+            &lines.password
+        };
+        if *status != self.header.status {
+            self.set_status(status.clone());
+        }
+    }
+
+    pub fn toggle_animations(&mut self) {
+        self.animations = !self.animations;
+        if !self.animations {
+            let (header_total, history_total) = (self.header_chars(), self.history_chars());
+            self.header_fx.finish(header_total);
+            self.history_fx.finish(history_total);
+            self.title.skip_boot();
+        }
+    }
+
+    // a new status line types out again after the title
+    pub fn set_status(&mut self, status: String) {
+        self.header.status = status;
+        if self.animations {
+            self.header_fx.rewind(self.header.title.chars().count());
         }
     }
+
+    // chars in the animated part of the header (title + status)
+    pub fn header_chars(&self) -> usize {
+        self.header.title.chars().count() + self.header.status.chars().count()
+    }
+    pub fn history_chars(&self) -> usize {
+        self.items.iter().map(|s| s.chars().count()).sum()
+    }
     pub fn table_up(&mut self) {
         if self.focus != Focus::Main { return; }
         
@@ -404,4 +1307,256 @@ impl App {
          .and_then(|row| row.get(x))
          .cloned()
     }
+
+    /* Enter on a word. a wrong guess costs an attempt and reports likeness,
+    *  the right one unlocks the terminal. returns false when the selected
+    *  cell has no word in it
+    */
+    pub fn submit_guess(&mut self) -> bool {
+        if self.game_over { return false; }
+        let Some(row) = self.state.selected() else { return false; };
+        let Some(cell) = TableModel::cell_index(row, self.col_state) else { return false; };
+        let Some(guess) = self.main.word_at_cell(cell) else { return false; };
+
+        let likeness = TableModel::likeness(&guess, &self.main.password);
+        self.main.guesses.push((guess.clone(), likeness));
+
+        self.items.push(format!(">{guess}"));
+        if self.header.apply_guess(&guess, &self.main.password) {
+            self.items.push(">Exact match!".to_string());
+            self.debug.push(format!("submit_guess guess={guess} unlocked"));
+            self.finish_game(true);
+            if self.run.is_some() {
+                self.next_terminal();
+            } else {
+                self.items.push(">Please wait while system is accessed.".to_string());
+                self.unlock();
+            }
+            return true;
+        }
+
+        self.items.push(">Entry denied.".to_string());
+        self.items.push(format!(">Likeness={likeness}"));
+        self.debug.push(format!("submit_guess guess={guess} likeness={likeness} left={}", self.header.health_i));
+        // the solver's view is only worth working out when the log is up
+        if self.show_debug {
+            let candidates = solver::consistent(&self.main.remaining_words(), &self.main.guesses);
+            for strategy in Strategy::ALL {
+                self.debug.push(format!(
+                    "solver {} candidates={} best={:?}", strategy.name(), candidates.len(), self.solver_hint(strategy),
+                ));
+            }
+        }
+
+        if self.header.is_out() {
+            self.game_over = true;
+            self.finish_game(false);
+        }
+        self.refresh_status();
+        true
+    }
+
+    // everything needed to put this board back on screen next launch
+    pub fn snapshot(&self) -> SaveGame {
+        SaveGame {
+            version: crate::save::SAVE_VERSION,
+            brand: self.title.brand,
+            daily: self.daily,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            history: self.items.clone(),
+            header: self.header.clone(),
+            board: self.main.clone(),
+            run: self.run.clone(),
+        }
+    }
+
+    pub fn resume(&mut self, save: SaveGame) {
+        self.title = TitleScreen::with_brand(&self.content, save.brand);
+        self.title.skip_boot();
+        self.daily = save.daily;
+        self.header = save.header;
+        self.main = save.board;
+        self.run = save.run;
+        self.items = save.history;
+        self.input.clear();
+        self.game_over = false;
+        self.show_hints = false;
+        self.show_tracker = false;
+        self.state.select(Some(0));
+        self.col_state = 0;
+        self.refresh_table();
+        self.started = Instant::now()
+            .checked_sub(std::time::Duration::from_millis(save.elapsed_ms))
+            .unwrap_or_else(Instant::now);
+
+        // nothing to type out again, the player has seen it all before
+        let (header_total, history_total) = (self.header_chars(), self.history_chars());
+        self.header_fx.finish(header_total);
+        self.history_fx.finish(history_total);
+
+        self.screen = Screen::Board;
+        self.debug.push(format!("resume difficulty={} guesses={}", self.main.difficulty.name(), self.main.guesses.len()));
+    }
+
+    // candidate tracker: lists candidates in the Side panel and dims ruled
+    // out words on the board while it's open
+    pub fn toggle_tracker(&mut self) {
+        self.show_tracker = !self.show_tracker;
+    }
+
+    // whether ruled out words should be dimmed on the board, the tracker does
+    // it while open, Hint::Eliminated for the rest of the board
+    pub fn dim_eliminated(&self) -> bool {
+        self.show_tracker || self.main.show_eliminated
+    }
+
+    /* spends an attempt on a hint. refused on daily boards, once the budget
+    *  is used up, or when paying for it would lock the terminal
+    */
+    pub fn use_hint(&mut self, hint: Hint) -> bool {
+        let refusal = if self.daily {
+            Some(">Hints are disabled on daily terminals.")
+        } else if self.main.hints_used >= HINT_BUDGET {
+            Some(">No hints left.")
+        } else if self.header.health_i <= HINT_COST {
+            Some(">Not enough attempts for a hint.")
+        } else {
+            None
+        };
+        if let Some(refusal) = refusal {
+            self.items.push(refusal.to_string());
+            return false;
+        }
+
+        let message = match hint {
+            Hint::Eliminated if self.main.show_eliminated => None,
+            Hint::Eliminated => {
+                self.main.show_eliminated = true;
+                Some(">Hint: eliminated words dimmed.".to_string())
+            }
+            Hint::BestGuess => self.solver_hint(Strategy::default())
+                .map(|word| format!(">Hint: try {word}")),
+            Hint::Letter => {
+                let hidden: Vec<usize> = (0..self.main.password.chars().count())
+                    .filter(|i| !self.main.revealed.contains(i))
+                    .collect();
+                if hidden.is_empty() {
+                    None
+                } else {
+                    let i = hidden[fastrand::usize(..hidden.len())];
+                    self.main.revealed.push(i);
+                    let c = self.main.password.chars().nth(i).unwrap_or('?');
+                    Some(format!(">Hint: letter {} is {c}", i + 1))
+                }
+            }
+        };
+        let Some(message) = message else {
+            self.items.push(">Nothing more that hint can tell you.".to_string());
+            return false;
+        };
+
+        self.items.push(message);
+        self.main.hints_used += 1;
+        self.header.health_i -= HINT_COST;
+        self.refresh_status();
+        self.debug.push(format!("use_hint hint={hint:?} used={}", self.main.hints_used));
+        true
+    }
+
+    // what the solver would guess next on this board
+    pub fn solver_hint(&self, strategy: Strategy) -> Option<String> {
+        solver::best_guess(
+            &self.main.guess_pool(),
+            &self.main.remaining_words(),
+            &self.main.guesses,
+            strategy,
+        )
+    }
+
+    // record a won or locked-out board in the player's stats
+    pub fn finish_game(&mut self, won: bool) {
+        if won {
+            self.profile.hacked();
+            self.profile_dirty = true;
+        }
+        // timed run terminals count as timed games, the run itself goes
+        // into the stats once, when it ends
+        if self.run.is_some() && let Some(clock) = self.header.clock {
+            self.stats.record_timed(self.main.difficulty, won, clock.left_ms);
+            self.stats_dirty = true;
+        }
+        if let Some(run) = &mut self.run {
+            run.guesses += self.main.guesses.len() as u32;
+            run.brackets_used += self.main.brackets_used as u32;
+            if won {
+                run.crack(self.header.health_i);
+            } else {
+                self.stats.record_run(run);
+                self.stats_dirty = true;
+            }
+            return;
+        }
+
+        let result = GameResult {
+            difficulty: self.main.difficulty,
+            won,
+            attempts_used: self.main.guesses.len() as u32,
+            brackets_used: self.main.brackets_used as u32,
+            elapsed_ms: self.started.elapsed().as_millis() as u64,
+            time_left_ms: self.header.clock.map(|clock| clock.left_ms),
+        };
+        self.stats.record(&result);
+        self.stats_dirty = true;
+    }
+
+    // open up the brand's entries and switch over to the unlocked terminal
+    pub fn unlock(&mut self) {
+        self.set_status(self.content.status.granted.clone());
+        let entries = self.content.brand(self.title.brand).entries.clone();
+        self.terminal = TerminalScreen::new(entries);
+        self.screen = Screen::Terminal;
+    }
+
+    // rebuild the rendered rows after the board underneath changed
+    pub fn refresh_table(&mut self) {
+        self.ts.fit_hex(&self.main.hex_list, &self.hex_prefix);
+        match TableModel::build_alternating_lists(
+            self.ts.columns, self.ts.rows, &self.main.hex_list, &self.main.play_space,
+        ) {
+            Ok(rows) => self.table_contents = rows,
+            Err(e) => self.debug.push(format!("refresh_table error={e:#}")),
+        }
+    }
+
+    /* Enter on a cell holding a bracket pair. most of the time a dud is
+    *  removed, otherwise (or once the duds run out) attempts are refilled.
+    *  returns false when the selected cell has no usable pair.
+    */
+    pub fn use_brackets(&mut self) -> bool {
+        if self.game_over { return false; }
+        let Some(row) = self.state.selected() else { return false; };
+        let Some(cell) = TableModel::cell_index(row, self.col_state) else { return false; };
+        if !self.main.consume_brackets(cell) { return false; }
+
+        let hurt = self.header.health_i < self.header.max_health;
+        let removed = if hurt && fastrand::u8(..4) == 0 { None } else { self.main.remove_dud() };
+
+        match removed {
+            Some(dud) => {
+                self.items.push(self.content.history.dud_removed.clone());
+                self.debug.push(format!("use_brackets cell={cell} removed={dud}"));
+            }
+            None => {
+                self.header.replenish();
+                self.refresh_status();
+                self.items.push(self.content.history.replenished.clone());
+                self.debug.push(format!("use_brackets cell={cell} replenished"));
+            }
+        }
+        self.refresh_table();
+        true
+    }
 }
+
+#[cfg(test)]
+mod tests;
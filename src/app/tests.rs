@@ -0,0 +1,227 @@
+// src/app/tests.rs
+// Property tests for board generation: whatever the seed or the word list,
+// a dealt board fills exactly BOARD_CHARS, every word sits on it once and
+// intact, the password is one of them and planted bracket pairs parse. Junk
+// gaps stay within JUNK_VARIANCE_PCT of the even share and the halves carry
+// a similar load, and every word owns a cell Enter can pick it from. Word
+// lists that can't fit come back as errors.
+
+use proptest::prelude::*;
+
+use super::{
+    Difficulty, TableModel, BOARD_CHARS, CELL_LEN, HALF_CHARS, JUNK_VARIANCE_PCT, MIN_JUNK_GAP, TOTAL_CELLS,
+};
+use crate::content::{Content, Lock, CONTENT_PATH};
+
+// generation errors fail the case instead of panicking out of proptest
+fn ok<T>(result: anyhow::Result<T>) -> Result<T, TestCaseError> {
+    result.map_err(|e| TestCaseError::fail(format!("{e:#}")))
+}
+
+// the board a junk_word_list lays out, flattened
+fn flatten(junk_word_list: &[String]) -> String {
+    junk_word_list.concat()
+}
+
+// what every junk_word_list coming out of generate_junk has to look like
+fn check_junk(junk_word_list: &[String], word_list: &[String]) -> Result<(), TestCaseError> {
+    prop_assert_eq!(junk_word_list.len(), word_list.len() * 2 + 1);
+
+    let board = flatten(junk_word_list);
+    prop_assert_eq!(board.chars().count(), BOARD_CHARS);
+
+    for (i, segment) in junk_word_list.iter().enumerate() {
+        if i % 2 == 1 {
+            prop_assert_eq!(segment, &word_list[i / 2], "word {} moved or was cut", i / 2);
+        } else {
+            prop_assert!(!segment.chars().any(char::is_alphabetic), "letters in junk {:?}", segment);
+            let inner = i > 0 && i < junk_word_list.len() - 1;
+            if inner {
+                prop_assert!(segment.chars().count() >= MIN_JUNK_GAP, "gap {} too narrow", i / 2);
+            }
+        }
+    }
+    for word in word_list {
+        prop_assert_eq!(board.matches(word.as_str()).count(), 1, "{} should be on the board once", word);
+    }
+    Ok(())
+}
+
+// no word may straddle the middle of the board, the two panels are apart on screen
+fn check_halves(board: &TableModel) -> Result<(), TestCaseError> {
+    let half = BOARD_CHARS / 2;
+    for span in board.word_spans() {
+        prop_assert!(span.end <= half || span.start >= half, "word at {:?} straddles the halves", span);
+    }
+    Ok(())
+}
+
+// every word has a cell where Enter picks it
+fn check_reachable(board: &TableModel) -> Result<(), TestCaseError> {
+    for word in &board.word_list {
+        let reachable = (0..TOTAL_CELLS).any(|cell| board.word_at_cell(cell).as_deref() == Some(word.as_str()));
+        prop_assert!(reachable, "{} can't be picked from any cell", word);
+    }
+    Ok(())
+}
+
+fn cell_has_pair(play_space: &[String], cell: usize) -> bool {
+    !TableModel::bracket_pairs(&play_space[cell]).is_empty()
+}
+
+// distinct words of one length, as a lock would deal them
+fn word_lists() -> impl Strategy<Value = Vec<String>> {
+    (1usize..=12, 0usize..=30).prop_flat_map(|(len, count)| {
+        let word = prop::string::string_regex(&format!("[a-z]{{{len}}}")).expect("valid regex");
+        prop::collection::hash_set(word, 0..=count)
+            .prop_map(|words| words.into_iter().collect::<Vec<_>>())
+    })
+}
+
+fn difficulties() -> impl Strategy<Value = Difficulty> {
+    prop::sample::select(Difficulty::ALL.to_vec())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn dealt_boards_hold_together(seed: u64, difficulty in difficulties()) {
+        let content = Content::load(CONTENT_PATH).expect("assets/content.toml should load");
+        let lock = content.lock(difficulty);
+        fastrand::seed(seed);
+        let board = ok(TableModel::new(difficulty, lock))?;
+
+        prop_assert_eq!(board.word_list.len(), lock.word_count);
+        prop_assert!(board.word_list.iter().all(|w| w.chars().count() == lock.word_len));
+        prop_assert!(board.word_list.contains(&board.password));
+        check_junk(&board.junk_word_list, &board.word_list)?;
+        check_halves(&board)?;
+        check_reachable(&board)?;
+
+        prop_assert_eq!(board.play_space.len(), TOTAL_CELLS);
+        prop_assert!(board.play_space.iter().all(|cell| cell.chars().count() == CELL_LEN));
+        prop_assert_eq!(board.play_space.concat(), flatten(&board.junk_word_list));
+        let pairs = (0..TOTAL_CELLS).filter(|&cell| cell_has_pair(&board.play_space, cell)).count();
+        prop_assert!(pairs >= lock.bracket_pairs, "{} cells with pairs, lock wants {}", pairs, lock.bracket_pairs);
+    }
+
+    #[test]
+    fn every_word_is_reachable(seed: u64, word_len in 4usize..=12, word_count in 2usize..=40) {
+        let lock = Lock { word_len, word_count, bracket_pairs: 0, attempts: 4, solvable: false };
+        prop_assume!(lock.validate().is_ok());
+        fastrand::seed(seed);
+        // the word list may not have enough words of some lengths
+        let Ok(board) = TableModel::new(Difficulty::Novice, &lock) else { return Ok(()); };
+        check_reachable(&board)?;
+    }
+
+    #[test]
+    fn any_word_list_fits_or_errors(seed: u64, words in word_lists(), pairs in 0usize..8) {
+        fastrand::seed(seed);
+        // words are all one length, so the fuller half decides: k words
+        // there need k + 1 gaps of at least MIN_JUNK_GAP around them
+        let len = words.first().map_or(0, |w| w.chars().count());
+        let fuller = words.len().div_ceil(2);
+        let fits = fuller * (len + MIN_JUNK_GAP) + MIN_JUNK_GAP <= HALF_CHARS;
+
+        let generated = TableModel::generate_junk(&words);
+        prop_assert_eq!(generated.is_ok(), fits, "{} words of {} letters", words.len(), len);
+        let Ok(mut junk_word_list) = generated else { return Ok(()); };
+        check_junk(&junk_word_list, &words)?;
+
+        let planted = ok(TableModel::plant_brackets(&mut junk_word_list, pairs))?;
+        prop_assert!(planted.len() <= pairs);
+        check_junk(&junk_word_list, &words)?;
+
+        let play_space = ok(TableModel::build_play_space(&junk_word_list))?;
+        for &cell in &planted {
+            prop_assert!(cell_has_pair(&play_space, cell), "planted pair in cell {} doesn't parse", cell);
+        }
+
+        match TableModel::new_password(&words) {
+            Ok(password) => prop_assert!(words.contains(&password)),
+            Err(_) => prop_assert!(words.is_empty()),
+        }
+    }
+
+    #[test]
+    fn junk_gaps_stay_near_the_even_share(seed: u64, words in word_lists()) {
+        fastrand::seed(seed);
+        prop_assume!(TableModel::fits_in_half(&words));
+        let gaps = TableModel::distribute_junk(&words);
+        let word_chars: usize = words.iter().map(|w| w.chars().count()).sum();
+        prop_assert_eq!(gaps.len(), words.len() + 1);
+        prop_assert_eq!(gaps.iter().sum::<usize>() + word_chars, HALF_CHARS);
+
+        let share = (HALF_CHARS - word_chars) / gaps.len();
+        let variance = share * JUNK_VARIANCE_PCT / 100;
+        let lo = share.saturating_sub(variance).max(MIN_JUNK_GAP);
+        for &gap in &gaps {
+            prop_assert!((lo..=share + 1 + variance).contains(&gap), "gap {} strays from share {}", gap, share);
+        }
+    }
+
+    #[test]
+    fn halves_split_evenly(seed: u64, words in word_lists()) {
+        fastrand::seed(seed);
+        let Ok(split) = TableModel::split_halves(&words) else { return Ok(()); };
+        let (left, right) = words.split_at(split);
+        prop_assert!(TableModel::fits_in_half(left) && TableModel::fits_in_half(right));
+        // one word of the same length either way is as close as it gets
+        let len = words.first().map_or(0, |w| w.chars().count());
+        let chars = |half: &[String]| half.len() * len;
+        prop_assert!(chars(left).abs_diff(chars(right)) <= len, "{} vs {} chars", chars(left), chars(right));
+    }
+
+    #[test]
+    fn play_space_needs_a_whole_board(lens in prop::collection::vec(0usize..=64, 0..12)) {
+        let junk_word_list: Vec<String> = lens.iter().map(|&len| ".".repeat(len)).collect();
+        let total: usize = lens.iter().sum();
+        match TableModel::build_play_space(&junk_word_list) {
+            Ok(play_space) => {
+                prop_assert_eq!(total, BOARD_CHARS);
+                prop_assert_eq!(play_space.len(), TOTAL_CELLS);
+            }
+            Err(_) => prop_assert_ne!(total, BOARD_CHARS),
+        }
+    }
+}
+
+#[test]
+fn too_many_words_is_an_error() {
+    // 40 eight letter words can't leave two junk chars between each
+    let words = vec!["password".to_string(); 40];
+    assert!(TableModel::generate_junk(&words).is_err());
+}
+
+#[test]
+fn empty_word_list_has_no_password() {
+    assert!(TableModel::new_password(&[]).is_err());
+}
+
+#[test]
+fn daily_board_leaves_the_session_rng_alone() {
+    let mut app = crate::testing::app(30);
+    assert!(app.start_board(crate::title::DAILY_DIFFICULTY, true));
+    let daily = app.main.word_list.clone();
+    let after_daily = fastrand::u64(..);
+
+    // a different session deals the same daily board
+    let mut other = crate::testing::app(31);
+    assert!(other.start_board(crate::title::DAILY_DIFFICULTY, true));
+    assert_eq!(other.main.word_list, daily);
+
+    // and the first session's sequence carries on as if it never happened
+    crate::testing::app(30);
+    assert_eq!(fastrand::u64(..), after_daily);
+}
+
+#[test]
+fn unsolvable_deals_are_logged() {
+    let mut app = crate::testing::app(40);
+    // one attempt can never be sure of eight words
+    app.content.lock_mut(Difficulty::Novice).attempts = 1;
+    assert!(app.start_board(Difficulty::Novice, false));
+    assert!(app.debug.iter().any(|line| line.starts_with("deal unsolvable")));
+}
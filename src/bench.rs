@@ -0,0 +1,138 @@
+// src/bench.rs
+// Headless bot player for `--bench N` and `--headless`. Deals N seeded boards
+// per lock level, plays each one with every bot and prints win rates, so word
+// selection and attempt counts can be tuned with data instead of guesswork.
+
+use anyhow::Result;
+
+use crate::app::{Difficulty, TableModel};
+use crate::content::{Content, Lock};
+use crate::solver::{self, Strategy};
+
+// Guessing strategies. None of them use bracket pairs
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Bot {
+    Random,  // any word not guessed yet
+    Greedy,  // any word that could still be the password
+    Minimax, // solver, smallest worst case
+    Expected, // solver, smallest expected case
+}
+impl Bot {
+    pub const ALL: [Bot; 4] = [Bot::Random, Bot::Greedy, Bot::Minimax, Bot::Expected];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Bot::Random   => "random",
+            Bot::Greedy   => "greedy",
+            Bot::Minimax  => "minimax",
+            Bot::Expected => "expected",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<Bot> {
+        Bot::ALL.into_iter().find(|b| b.name() == name)
+    }
+
+    pub fn guess(self, board: &TableModel) -> Option<String> {
+        let pool = board.guess_pool();
+        let pick = |words: &[String]| -> Option<String> {
+            if words.is_empty() { return None; }
+            Some(words[fastrand::usize(..words.len())].clone())
+        };
+        match self {
+            Bot::Random => pick(&pool),
+            Bot::Greedy => pick(&solver::consistent(&pool, &board.guesses)),
+            Bot::Minimax => solver::best_guess(&pool, &board.remaining_words(), &board.guesses, Strategy::Minimax),
+            Bot::Expected => solver::best_guess(&pool, &board.remaining_words(), &board.guesses, Strategy::Expected),
+        }
+    }
+}
+
+
+// Outcome of one bot on one board
+pub struct Played {
+    pub won: bool,
+    pub guesses: usize,
+}
+
+// one board to the end, `attempts` wrong guesses locks it
+pub fn play(board: &mut TableModel, bot: Bot, attempts: u8) -> Played {
+    let mut wrong = 0u8;
+    while wrong < attempts {
+        let Some(guess) = bot.guess(board) else { break; };
+        let likeness = TableModel::likeness(&guess, &board.password);
+        board.guesses.push((guess.clone(), likeness));
+        if guess == board.password {
+            return Played { won: true, guesses: board.guesses.len() };
+        }
+        wrong += 1;
+    }
+    Played { won: false, guesses: board.guesses.len() }
+}
+
+pub struct BenchRow {
+    pub difficulty: Difficulty,
+    pub bot: Bot,
+    pub boards: usize,
+    pub wins: usize,
+    pub guesses: usize,      // over every board
+    pub win_guesses: usize,  // over won boards only
+}
+
+/* board i of every difficulty is dealt from seed + i, and each bot replays
+*  the exact same boards so the rows are comparable
+*/
+pub fn run(content: &Content, difficulties: &[Difficulty], boards: usize, bots: &[Bot], seed: u64) -> Result<Vec<BenchRow>> {
+    let mut rows = Vec::new();
+    for &difficulty in difficulties {
+        let lock = content.lock(difficulty);
+        for &bot in bots {
+            rows.push(bench_one(difficulty, lock, bot, boards, seed)?);
+        }
+    }
+    Ok(rows)
+}
+
+fn bench_one(difficulty: Difficulty, lock: &Lock, bot: Bot, boards: usize, seed: u64) -> Result<BenchRow> {
+    let mut row = BenchRow { difficulty, bot, boards, wins: 0, guesses: 0, win_guesses: 0 };
+    for i in 0..boards {
+        fastrand::seed(seed.wrapping_add(i as u64));
+        let mut board = TableModel::new(difficulty, lock)?;
+        let played = play(&mut board, bot, lock.attempts);
+        row.guesses += played.guesses;
+        if played.won {
+            row.wins += 1;
+            row.win_guesses += played.guesses;
+        }
+    }
+    Ok(row)
+}
+
+// --headless, one board from `seed` played out by each bot, guess by guess
+pub fn headless(content: &Content, difficulty: Difficulty, bots: &[Bot], seed: u64) -> Result<()> {
+    let lock = content.lock(difficulty);
+    println!("{} board, seed {seed}", difficulty.name());
+    for &bot in bots {
+        fastrand::seed(seed);
+        let mut board = TableModel::new(difficulty, lock)?;
+        let played = play(&mut board, bot, lock.attempts);
+        println!("{}: password {}", bot.name(), board.password);
+        for (i, (guess, likeness)) in board.guesses.iter().enumerate() {
+            println!("  {}. {guess} likeness={likeness}", i + 1);
+        }
+        println!("  {} in {} guesses", if played.won { "unlocked" } else { "locked out" }, played.guesses);
+    }
+    Ok(())
+}
+
+pub fn print_report(rows: &[BenchRow]) {
+    println!("{:<10} {:<9} {:>6} {:>7} {:>12} {:>12}", "lock", "bot", "boards", "win%", "avg guesses", "avg to win");
+    for row in rows {
+        let win_rate = row.wins as f64 * 100.0 / row.boards.max(1) as f64;
+        let avg = row.guesses as f64 / row.boards.max(1) as f64;
+        let avg_win = if row.wins == 0 { 0.0 } else { row.win_guesses as f64 / row.wins as f64 };
+        println!(
+            "{:<10} {:<9} {:>6} {:>6.1}% {:>12.2} {:>12.2}",
+            row.difficulty.name().to_lowercase(), row.bot.name(), row.boards, win_rate, avg, avg_win,
+        );
+    }
+}
@@ -0,0 +1,98 @@
+// src/campaign.rs
+// Terminal run: an endless string of terminals from the title menu (or
+// `--run`). Each one cracked deals a harder one, longer words, more of them
+// and fewer bracket pairs, starting with a few attempts plus half of what was
+// left on the last. The run ends at the first lockout with a summary.
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::Difficulty;
+use crate::content::{Content, Lock, MAX_ATTEMPTS};
+
+pub const BASE_ATTEMPTS: u8 = 3; // every terminal after the first starts with these
+pub const MAX_WORD_LEN: usize = 12;
+pub const TERMINAL_POINTS: u32 = 100; // times the terminal's number
+pub const ATTEMPT_POINTS: u32 = 25;   // per attempt left when it's cracked
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Run {
+    pub lock: Lock,    // the terminal being hacked now
+    pub score: u32,
+    pub guesses: u32,  // over every terminal, the current one once it's done
+    pub brackets_used: u32,
+    pub cracked: Vec<Cracked>,
+}
+
+// One line of the summary
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cracked {
+    pub word_len: usize,
+    pub word_count: usize,
+    pub attempts_left: u8,
+    pub points: u32,
+}
+
+impl Run {
+    // the first terminal is the content's novice lock, overrides included
+    pub fn new(content: &Content) -> Self {
+        Self {
+            lock: content.lock(Difficulty::Novice).clone(),
+            score: 0,
+            guesses: 0,
+            brackets_used: 0,
+            cracked: Vec::new(),
+        }
+    }
+
+    // 1 based, the terminal being hacked now
+    pub fn terminal(&self) -> usize {
+        self.cracked.len() + 1
+    }
+
+    // score the current terminal, returns its points
+    pub fn crack(&mut self, attempts_left: u8) -> u32 {
+        let points = TERMINAL_POINTS * self.terminal() as u32 + ATTEMPT_POINTS * attempts_left as u32;
+        self.cracked.push(Cracked {
+            word_len: self.lock.word_len,
+            word_count: self.lock.word_count,
+            attempts_left,
+            points,
+        });
+        self.score += points;
+        points
+    }
+
+    /* the lock after the current one: a letter longer every terminal, and
+    *  every second one a word more and a bracket pair less. word counts that
+    *  no longer fit the board are trimmed back
+    */
+    pub fn harder(&self) -> Lock {
+        let mut next = self.lock.clone();
+        next.word_len = (next.word_len + 1).min(MAX_WORD_LEN);
+        if self.terminal().is_multiple_of(2) {
+            next.word_count += 1;
+            next.bracket_pairs = next.bracket_pairs.saturating_sub(1);
+        }
+        while next.validate().is_err() && next.word_count > self.lock.word_count {
+            next.word_count -= 1;
+        }
+        if next.validate().is_err() { return self.lock.clone(); }
+        next
+    }
+
+    // a few attempts plus half of the ones saved, rounded up
+    pub fn carried_attempts(attempts_left: u8) -> u8 {
+        (BASE_ATTEMPTS + attempts_left.div_ceil(2)).min(MAX_ATTEMPTS)
+    }
+}
+
+// stats and the board label go by the hardest lock level with words this short
+pub fn difficulty_for(content: &Content, lock: &Lock) -> Difficulty {
+    Difficulty::ALL
+        .into_iter()
+        .rfind(|&d| content.lock(d).word_len <= lock.word_len)
+        .unwrap_or(Difficulty::Novice)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+// src/campaign/tests.rs
+// Lock progression and attempt carry-over, then a seeded run played through
+// App: cracking terminals, the score and the lockout that ends it.
+
+use super::{Run, ATTEMPT_POINTS, BASE_ATTEMPTS, MAX_WORD_LEN, TERMINAL_POINTS};
+use crate::app::{App, Screen};
+use crate::content::{Content, CONTENT_PATH, MAX_ATTEMPTS};
+use crate::profile::Perk;
+use crate::testing;
+
+const SEED: u64 = 49;
+
+fn run_app() -> App {
+    let mut app = testing::app(SEED);
+    if app.animations { app.toggle_animations(); }
+    assert!(app.start_run());
+    app
+}
+
+fn crack(app: &mut App) {
+    let password = app.main.password.clone();
+    assert!(testing::guess(app, &password));
+}
+
+#[test]
+fn locks_get_harder_and_still_fit() {
+    let content = Content::load(CONTENT_PATH).unwrap();
+    let mut run = Run::new(&content);
+    for _ in 0..20 {
+        let next = run.harder();
+        next.validate().unwrap();
+        assert!(next.word_len >= run.lock.word_len && next.word_len <= MAX_WORD_LEN);
+        assert!(next.bracket_pairs <= run.lock.bracket_pairs);
+        run.crack(0);
+        run.lock = next;
+    }
+    assert_eq!(run.lock.word_len, MAX_WORD_LEN);
+    assert_eq!(run.lock.bracket_pairs, 0);
+}
+
+#[test]
+fn attempts_carry_over_by_half() {
+    let carried: Vec<u8> = (0..=5).map(Run::carried_attempts).collect();
+    assert_eq!(carried, [BASE_ATTEMPTS, 4, 4, 5, 5, MAX_ATTEMPTS]);
+}
+
+#[test]
+fn cracking_deals_the_next_terminal() {
+    let mut app = run_app();
+    let first = app.run.as_ref().unwrap().lock.clone();
+    let attempts = app.header.health_i;
+
+    crack(&mut app);
+    assert_eq!(app.screen, Screen::Board);
+    assert!(!app.game_over);
+    assert!(app.main.guesses.is_empty());
+
+    let run = app.run.as_ref().unwrap();
+    assert_eq!(run.terminal(), 2);
+    assert_eq!(run.score, TERMINAL_POINTS + ATTEMPT_POINTS * attempts as u32);
+    assert_eq!(run.lock.word_len, first.word_len + 1);
+    assert_eq!(app.main.word_list[0].len(), run.lock.word_len);
+    assert_eq!(app.header.health_i, Run::carried_attempts(attempts));
+    assert!(app.items.iter().any(|item| item.contains("Terminal 1 cracked")));
+    assert_eq!(app.stats.games_played, 0);
+}
+
+#[test]
+fn lockout_ends_the_run() {
+    let mut app = run_app();
+    crack(&mut app);
+    crack(&mut app);
+    for dud in testing::duds(&app.main) {
+        if app.game_over { break; }
+        testing::guess(&mut app, &dud);
+    }
+    assert!(app.game_over);
+
+    let run = app.run.as_ref().unwrap();
+    assert_eq!(run.cracked.len(), 2);
+    assert_eq!(app.stats.runs_played, 1);
+    assert_eq!(app.stats.best_run_terminals, 2);
+    assert_eq!(app.stats.best_run_score, run.score);
+    assert_eq!(app.stats.games_played, 0);
+}
+
+#[test]
+fn nothing_left_to_deal_ends_the_run() {
+    let mut app = run_app();
+    // no 2 or 3 letter words to deal the next terminal (or this one again) from
+    app.run.as_mut().unwrap().lock.word_len = 2;
+    let password = app.main.password.clone();
+    crack(&mut app);
+
+    assert!(app.game_over);
+    assert_eq!(app.screen, Screen::Board);
+    let points = app.run.as_ref().unwrap().cracked[0].points;
+    assert_eq!(app.items[app.items.len() - 5..], [
+        format!(">{password}"),
+        ">Exact match!".to_string(),
+        format!(">Terminal 1 cracked, +{points}."),
+        ">No more terminals in reach.".to_string(),
+        ">Run over.".to_string(),
+    ]);
+    assert_eq!(app.run.as_ref().unwrap().cracked.len(), 1);
+    assert_eq!(app.stats.runs_played, 1);
+    assert_eq!(app.stats.best_run_terminals, 1);
+}
+
+#[test]
+fn next_terminal_history_opens_with_the_last_one() {
+    let mut app = run_app();
+    app.profile.perks.push(Perk::AutoRemoveDud);
+    crack(&mut app);
+
+    let points = app.run.as_ref().unwrap().cracked[0].points;
+    assert_eq!(app.items, [
+        format!(">Terminal 1 cracked, +{points}."),
+        ">Connecting to terminal 2...".to_string(),
+        ">Perk: dud removed.".to_string(),
+    ]);
+}
@@ -0,0 +1,253 @@
+// src/cli.rs
+// Command line options. Everything is parsed and checked (files read, ranges
+// validated) before the terminal switches to the TUI, so a typo prints as a
+// plain error instead of flashing past on the alternate screen.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::app::Difficulty;
+use crate::bench::Bot;
+use crate::clock::{MAX_SECS, MIN_SECS};
+use crate::config::{self, Layer, Settings};
+use crate::content::{self, Content, MAX_ATTEMPTS};
+use crate::keymap::{KeyBindings, Keymap};
+use crate::replay::{MAX_SPEED, MIN_SPEED};
+use crate::theme::ThemeKind;
+
+pub const USAGE: &str = "\
+usage: fallout-game [options]
+
+play
+  --difficulty <novice|advanced|expert|master>  skip the title, start a board
+  --daily                    skip the title, start today's daily board
+  --run                      skip the title, start a terminal run
+  --seed <n>                 deal from a fixed seed instead of a random one
+  --attempts <1-5>           attempts for every lock level
+  --geometry <len>x<count>   word length and words per board, e.g. 7x10
+  --timed <seconds>          timed mode, every board locks when the clock runs out
+  --words <file>             word list, one word per line
+  --theme <green|amber|white>
+  --keymap <file>            TOML rebinding quit, help, hints, tracker, debug,
+                             animations, theme, left, down, up, right
+
+modes
+  --headless                 play one board with a bot and print the guesses
+  --bench <n>                play n boards per lock level with each bot
+  --bot <random|greedy|minimax|expected>  bots for --headless/--bench, repeatable
+  --replay <file> [--speed <0.25-16>]  play back a recorded session
+  --print-config             print the merged settings and where they came from
+  --help
+
+settings are layered: defaults, then <config dir>/fallout-game/config.toml,
+then ./fallout-game.toml, then the flags above. see --print-config
+";
+
+/* options that change which boards get dealt. a replay records them next to
+*  its seed so playing it back deals the same boards whatever the command line
+*/
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    pub difficulty: Option<Difficulty>, // start on this board
+    pub daily: bool,                    // start on the daily board
+    pub run: bool,                      // start a terminal run
+    pub attempts: Option<u8>,
+    pub geometry: Option<Geometry>,
+    pub words: Option<PathBuf>,
+    pub time_limit: Option<u64>,        // timed mode, seconds per board
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Geometry {
+    pub word_len: usize,
+    pub word_count: usize,
+}
+
+pub enum Mode {
+    Play,
+    Headless { bots: Vec<Bot> },
+    Bench { boards: usize, bots: Vec<Bot> },
+    Replay { path: PathBuf, speed: f64 },
+    PrintConfig,
+    Help,
+}
+
+pub struct Options {
+    pub settings: Settings, // every config layer and flag merged
+    pub rules: Rules,       // the board-shaping part of settings
+    pub keymap: Keymap,
+    pub mode: Mode,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Options> {
+    parse_with(args, &config::layer_files())
+}
+
+// parse with the config layers read from `files`, tests pass none so the
+// real config files can't change what they see
+pub fn parse_with(args: impl IntoIterator<Item = String>, files: &[PathBuf]) -> Result<Options> {
+    let mut flags = Layer::default();
+    let mut daily = false;
+    let mut run = false;
+    let mut print_config = false;
+    let mut headless = false;
+    let mut bench: Option<usize> = None;
+    let mut bots: Vec<Bot> = Vec::new();
+    let mut replay: Option<PathBuf> = None;
+    let mut speed = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().with_context(|| format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--difficulty" => {
+                let v = value()?;
+                flags.game.difficulty = Some(Difficulty::from_name(&v)
+                    .with_context(|| format!("--difficulty: {v:?} is not one of {}", names(Difficulty::ALL.map(|d| d.name()))))?);
+            }
+            "--daily" => { daily = true; }
+            "--run" => { run = true; }
+            "--seed" => { flags.game.seed = Some(number(&arg, &value()?)?); }
+            "--attempts" => {
+                let v = value()?;
+                let attempts: u8 = number(&arg, &v)?;
+                if !(1..=MAX_ATTEMPTS).contains(&attempts) {
+                    bail!("--attempts: {attempts} must be between 1 and {MAX_ATTEMPTS}");
+                }
+                flags.game.attempts = Some(attempts);
+            }
+            "--geometry" => { flags.game.geometry = Some(geometry(&value()?)?); }
+            "--words" => { flags.game.words = Some(PathBuf::from(value()?)); }
+            "--timed" => {
+                let v = value()?;
+                let secs: u64 = number(&arg, &v)?;
+                if !(MIN_SECS..=MAX_SECS).contains(&secs) {
+                    bail!("--timed: {secs} must be between {MIN_SECS} and {MAX_SECS} seconds");
+                }
+                flags.game.time_limit = Some(secs);
+            }
+            "--theme" => {
+                let v = value()?;
+                flags.display.theme = Some(ThemeKind::from_name(&v)
+                    .with_context(|| format!("--theme: {v:?} is not one of {}", names(ThemeKind::ALL.map(|t| t.name()))))?);
+            }
+            "--keymap" => {
+                let v = value()?;
+                flags.keys = KeyBindings::load(Path::new(&v)).context("--keymap")?;
+            }
+            "--headless" => { headless = true; }
+            "--bench" => { bench = Some(number(&arg, &value()?)?); }
+            "--bot" => {
+                let v = value()?;
+                bots.push(Bot::from_name(&v)
+                    .with_context(|| format!("--bot: {v:?} is not one of {}", names(Bot::ALL.map(|b| b.name()))))?);
+            }
+            "--replay" => { replay = Some(PathBuf::from(value()?)); }
+            "--speed" => {
+                let v = value()?;
+                let x: f64 = number(&arg, &v)?;
+                // also turns away nan and inf, which parse as numbers
+                if !(MIN_SPEED..=MAX_SPEED).contains(&x) {
+                    bail!("--speed: {v} must be between {MIN_SPEED} and {MAX_SPEED}");
+                }
+                speed = Some(x);
+            }
+            "--print-config" => { print_config = true; }
+            "--help" | "-h" => {
+                let settings = Settings::default();
+                return Ok(Options { settings, rules: Rules::default(), keymap: Keymap::default(), mode: Mode::Help });
+            }
+            other => bail!("unknown argument {other:?}, see --help"),
+        }
+    }
+
+    let modes = [headless, bench.is_some(), replay.is_some(), print_config];
+    if modes.iter().filter(|m| **m).count() > 1 {
+        bail!("--headless, --bench, --replay and --print-config can't be combined");
+    }
+    if daily && flags.game.difficulty.is_some() {
+        bail!("--daily already picks the difficulty, drop --difficulty");
+    }
+    if run && (daily || flags.game.difficulty.is_some()) {
+        bail!("--run picks its own terminals, drop --daily and --difficulty");
+    }
+    if !bots.is_empty() && !headless && bench.is_none() {
+        bail!("--bot only applies to --headless and --bench");
+    }
+    if speed.is_some() && replay.is_none() {
+        bail!("--speed only applies to --replay");
+    }
+
+    let mode = if let Some(path) = replay {
+        Mode::Replay { path, speed: speed.unwrap_or(1.0) }
+    } else if let Some(boards) = bench {
+        if bots.is_empty() { bots = Bot::ALL.to_vec(); }
+        Mode::Bench { boards, bots }
+    } else if headless {
+        if bots.is_empty() { bots = vec![Bot::Minimax]; }
+        Mode::Headless { bots }
+    } else if print_config {
+        Mode::PrintConfig
+    } else {
+        Mode::Play
+    };
+
+    let settings = Settings::load(files, flags)?;
+    let keymap = Keymap::new(&settings.keys)?;
+    let game = &settings.game;
+    let rules = Rules {
+        // --daily/--run on the command line beat a difficulty from a config file
+        difficulty: if daily || run { None } else { game.difficulty },
+        daily,
+        run,
+        attempts: game.attempts,
+        geometry: game.geometry,
+        words: game.words.clone(),
+        time_limit: game.time_limit,
+    };
+    Ok(Options { settings, rules, keymap, mode })
+}
+
+fn number<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T> {
+    value.parse().ok().with_context(|| format!("{arg}: {value:?} is not a number"))
+}
+
+fn names<const N: usize>(names: [&str; N]) -> String {
+    names.join(", ").to_lowercase()
+}
+
+// "7x10" -> 7 letter words, 10 per board
+fn geometry(value: &str) -> Result<Geometry> {
+    let parsed = value
+        .split_once('x')
+        .and_then(|(len, count)| Some(Geometry { word_len: len.parse().ok()?, word_count: count.parse().ok()? }));
+    parsed.with_context(|| format!("--geometry: {value:?} should look like 7x10 (length x count)"))
+}
+
+impl Rules {
+    /* fold the overrides into every lock level and load the word list.
+    *  returns the words so main can hand them to the board dealer
+    */
+    pub fn apply(&self, content: &mut Content) -> Result<Vec<String>> {
+        for d in Difficulty::ALL {
+            let lock = content.lock_mut(d);
+            if let Some(attempts) = self.attempts { lock.attempts = attempts; }
+            if let Some(g) = self.geometry {
+                lock.word_len = g.word_len;
+                lock.word_count = g.word_count;
+                lock.validate().with_context(|| format!("game.geometry = {}x{}", g.word_len, g.word_count))?;
+            }
+        }
+
+        let path = self.words.clone().unwrap_or_else(|| PathBuf::from(content::WORDS_PATH));
+        let words = content::load_words(&path)?;
+        content.check_words(&words).with_context(|| format!("word list {}", path.display()))?;
+        Ok(words)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+// src/cli/tests.rs
+// Command lines that have to be turned away before the TUI starts, and the
+// ones that have to get through.
+
+use super::{parse_with, Geometry, Mode, Options, Rules};
+use crate::content::{Content, CONTENT_PATH};
+
+fn args(line: &str) -> anyhow::Result<Options> {
+    parse_with(line.split_whitespace().map(String::from), &[])
+}
+
+// the error for `line`, which has to mention `says`
+fn refused(line: &str, says: &str) {
+    match args(line) {
+        Ok(_) => panic!("{line:?} should be refused"),
+        Err(e) => assert!(format!("{e:#}").contains(says), "{line:?}: {e:#}"),
+    }
+}
+
+#[test]
+fn replay_speed_is_a_finite_number_in_range() {
+    for speed in ["nan", "NaN", "inf", "-inf", "0", "0.1", "17", "-2"] {
+        refused(&format!("--replay x.toml --speed {speed}"), "--speed");
+    }
+    refused("--replay x.toml --speed fast", "is not a number");
+
+    for speed in ["0.25", "1", "2.5", "16"] {
+        let options = args(&format!("--replay x.toml --speed {speed}")).unwrap();
+        let Mode::Replay { speed: parsed, .. } = options.mode else { panic!("--replay should replay") };
+        assert_eq!(parsed, speed.parse::<f64>().unwrap());
+    }
+}
+
+#[test]
+fn attempts_fit_the_health_bar() {
+    for attempts in ["0", "6", "255"] {
+        refused(&format!("--attempts {attempts}"), "must be between 1 and 5");
+    }
+    refused("--attempts -1", "is not a number");
+    refused("--attempts", "needs a value");
+    for attempts in [1, 5] {
+        assert_eq!(args(&format!("--attempts {attempts}")).unwrap().rules.attempts, Some(attempts));
+    }
+}
+
+#[test]
+fn geometry_is_length_x_count() {
+    for geometry in ["7", "7by10", "x10", "7x", "ax10", "7x10x2"] {
+        refused(&format!("--geometry {geometry}"), "should look like 7x10");
+    }
+    let g = args("--geometry 7x10").unwrap().rules.geometry.unwrap();
+    assert_eq!((g.word_len, g.word_count), (7, 10));
+}
+
+#[test]
+fn geometry_has_to_fit_every_lock() {
+    let apply = |word_len, word_count| {
+        let mut content = Content::load(CONTENT_PATH).unwrap();
+        let rules = Rules { geometry: Some(Geometry { word_len, word_count }), ..Rules::default() };
+        rules.apply(&mut content).map(|_| content)
+    };
+    let content = apply(4, 30).expect("4x30 fits");
+    assert_eq!(content.difficulty.master.word_count, 30);
+
+    assert!(apply(3, 10).is_err(), "no words that short");
+    assert!(apply(12, 20).is_err(), "too long to fit");
+    assert!(apply(4, 32).is_err(), "more words than lines");
+    assert!(apply(7, 1).is_err(), "no duds");
+}
+
+#[test]
+fn keymaps_are_checked() {
+    let path = std::env::temp_dir().join(format!("fallout-game-keymap-{}.toml", std::process::id()));
+    let keymap = |toml: &str| {
+        std::fs::write(&path, toml).unwrap();
+        args(&format!("--keymap {}", path.display()))
+    };
+    let err = |toml: &str| format!("{:#}", keymap(toml).err().unwrap_or_else(|| panic!("{toml:?} should be refused")));
+
+    assert!(err("quit = \"xy\"").contains("must be a single character"));
+    assert!(err("jump = \"x\"").contains("unknown field"));
+    assert!(err("quit = \"j\"").contains("already bound"));
+    assert!(err("hints = \"2\"").contains("taken by the hint and perk keys"));
+    assert!(keymap("quit = \"x\"").is_ok());
+    std::fs::remove_file(&path).ok();
+}
@@ -0,0 +1,67 @@
+// src/clock.rs
+// Timed mode. `--timed <seconds>` puts a countdown on every board, run off
+// App ticks (not the wall clock) so a replay locks out on the same frame.
+// The header draws it and the status line escalates as it runs low.
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::Difficulty;
+
+pub const MIN_SECS: u64 = 10;
+pub const MAX_SECS: u64 = 3600;
+pub const LOW_MS: u64 = 30_000;      // status switches to status.time_low
+pub const CRITICAL_MS: u64 = 10_000; // status switches to status.time_critical
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Stage {
+    Plenty,
+    Low,
+    Critical,
+    Expired,
+}
+
+// Saved with the Header, so a resumed board keeps the time it had left
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct Countdown {
+    pub limit_ms: u64,
+    pub left_ms: u64,
+}
+impl Countdown {
+    pub fn new(secs: u64) -> Self {
+        Self { limit_ms: secs * 1000, left_ms: secs * 1000 }
+    }
+
+    pub fn stage(&self) -> Stage {
+        match self.left_ms {
+            0 => Stage::Expired,
+            ms if ms <= CRITICAL_MS => Stage::Critical,
+            ms if ms <= LOW_MS => Stage::Low,
+            _ => Stage::Plenty,
+        }
+    }
+
+    // take `ms` off the clock, the new stage if that crossed into one
+    pub fn tick(&mut self, ms: u64) -> Option<Stage> {
+        let before = self.stage();
+        self.left_ms = self.left_ms.saturating_sub(ms);
+        let after = self.stage();
+        (after != before).then_some(after)
+    }
+
+    // "m:ss", rounded up so 0:00 only shows once time is really out
+    pub fn label(&self) -> String {
+        let secs = self.left_ms.div_ceil(1000);
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/* points for a timed win: whole seconds left times the lock level, novice 1
+*  up to master 4. a lockout scores nothing
+*/
+pub fn score(difficulty: Difficulty, left_ms: u64) -> u32 {
+    let level = Difficulty::ALL.iter().position(|&d| d == difficulty).unwrap_or(0) + 1;
+    (left_ms / 1000) as u32 * level as u32
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,144 @@
+// src/clock/tests.rs
+// Countdown stages and labels, and timed boards on a seeded App: running
+// out, the status line against the attempts left, and scoring inside a run.
+
+use super::{score, Countdown, Stage, CRITICAL_MS, LOW_MS};
+use crate::anim::TICK_RATE;
+use crate::app::{App, Difficulty, Screen};
+use crate::testing;
+
+// a novice board with `secs` on the clock
+fn timed_board(secs: u64) -> App {
+    let mut app = testing::app(48);
+    app.time_limit = Some(secs);
+    assert!(app.start_board(Difficulty::Novice, false));
+    app
+}
+
+// ticks until at least `ms` have come off the clock
+fn run_for(app: &mut App, ms: u64) {
+    for _ in 0..ms.div_ceil(TICK_RATE.as_millis() as u64) { app.on_tick(); }
+}
+
+#[test]
+fn stages_escalate_once_each() {
+    let mut clock = Countdown::new(40);
+    assert_eq!(clock.stage(), Stage::Plenty);
+    assert_eq!(clock.tick(40_000 - LOW_MS - 1), None);
+    assert_eq!(clock.tick(1), Some(Stage::Low));
+    assert_eq!(clock.tick(LOW_MS - CRITICAL_MS), Some(Stage::Critical));
+    assert_eq!(clock.tick(CRITICAL_MS - 1), None);
+    assert_eq!(clock.tick(5_000), Some(Stage::Expired));
+    assert_eq!(clock.left_ms, 0);
+    assert_eq!(clock.tick(1_000), None);
+}
+
+#[test]
+fn label_rounds_up() {
+    let mut clock = Countdown::new(90);
+    assert_eq!(clock.label(), "1:30");
+    clock.tick(29_001);
+    assert_eq!(clock.label(), "1:01");
+    clock.tick(60_998);
+    assert_eq!(clock.label(), "0:01");
+    clock.tick(1);
+    assert_eq!(clock.label(), "0:00");
+}
+
+#[test]
+fn score_scales_with_lock_level() {
+    assert_eq!(score(Difficulty::Novice, 12_900), 12);
+    assert_eq!(score(Difficulty::Master, 12_900), 48);
+    assert_eq!(score(Difficulty::Expert, 0), 0);
+}
+
+#[test]
+fn timed_board_locks_out() {
+    let mut app = testing::app(48);
+    app.time_limit = Some(10);
+    assert!(app.start_board(Difficulty::Novice, false));
+
+    let ticks = 10_000 / TICK_RATE.as_millis() as u64 + 1;
+    for _ in 0..ticks - 1 { app.on_tick(); }
+    assert!(!app.game_over);
+    assert_eq!(app.header.status, app.content.status.time_critical);
+
+    app.on_tick();
+    assert!(app.game_over);
+    assert_eq!(app.screen, Screen::Board);
+    assert_eq!(app.header.status, app.content.status.timeout);
+    assert_eq!(app.items.last().map(String::as_str), Some(">Session timed out."));
+    assert_eq!((app.stats.timed_played, app.stats.timed_won, app.stats.games_played), (1, 0, 1));
+
+    // a locked board doesn't keep counting
+    app.on_tick();
+    assert_eq!(app.stats.games_played, 1);
+}
+
+#[test]
+fn timed_win_scores() {
+    let mut app = testing::app(48);
+    app.time_limit = Some(60);
+    assert!(app.start_board(Difficulty::Advanced, false));
+    for _ in 0..100 { app.on_tick(); } // 3 seconds at 30ms a tick
+
+    let password = app.main.password.clone();
+    assert!(testing::guess(&mut app, &password));
+    assert_eq!(app.stats.timed_won, 1);
+    assert_eq!(app.stats.timed_score, 57 * 2);
+    assert_eq!(app.stats.best_timed_score, 57 * 2);
+}
+
+#[test]
+fn status_tracks_both_clock_and_attempts() {
+    let mut app = timed_board(40);
+    let status = app.content.status.clone();
+
+    run_for(&mut app, 40_000 - CRITICAL_MS);
+    assert_eq!(app.header.status, status.time_critical);
+
+    // down to the last attempt, the clock is still the more urgent
+    let attempts = usize::from(app.header.health_i);
+    for dud in testing::duds(&app.main).iter().take(attempts - 1) {
+        testing::guess(&mut app, dud);
+    }
+    assert_eq!(app.header.health_i, 1);
+    assert_eq!(app.header.status, status.time_critical);
+
+    run_for(&mut app, CRITICAL_MS);
+    assert_eq!(app.header.status, status.timeout);
+}
+
+#[test]
+fn last_attempt_outranks_low_time_until_it_goes_critical() {
+    let mut app = timed_board(40);
+    let status = app.content.status.clone();
+
+    let attempts = usize::from(app.header.health_i);
+    for dud in testing::duds(&app.main).iter().take(attempts - 1) {
+        testing::guess(&mut app, dud);
+    }
+    assert_eq!(app.header.status, status.warning);
+
+    run_for(&mut app, 40_000 - LOW_MS);
+    assert_eq!(app.header.status, status.warning, "low time doesn't hide the last attempt");
+    run_for(&mut app, LOW_MS - CRITICAL_MS);
+    assert_eq!(app.header.status, status.time_critical);
+}
+
+#[test]
+fn timed_run_terminals_are_scored() {
+    let mut app = testing::app(48);
+    app.time_limit = Some(60);
+    assert!(app.start_run());
+    for _ in 0..100 { app.on_tick(); } // 3 seconds at 30ms a tick
+
+    let difficulty = app.main.difficulty;
+    let password = app.main.password.clone();
+    assert!(testing::guess(&mut app, &password));
+    assert_eq!(app.run.as_ref().map(|run| run.cracked.len()), Some(1));
+    assert_eq!((app.stats.timed_played, app.stats.timed_won), (1, 1));
+    assert_eq!(app.stats.timed_score, score(difficulty, 57_000));
+    // the run terminal isn't a game of its own
+    assert_eq!(app.stats.games_played, 0);
+}
@@ -0,0 +1,179 @@
+// src/config.rs
+// Layered settings. Built-in defaults, then the user file
+// (<config dir>/fallout-game/config.toml), then ./fallout-game.toml in the
+// working directory, then command line flags, each layer overriding only the
+// keys it sets. The merged Settings is what App, TableStructure and ui read.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::app::Difficulty;
+use crate::cli::Geometry;
+use crate::clock::{MAX_SECS, MIN_SECS};
+use crate::content::MAX_ATTEMPTS;
+use crate::keymap::KeyBindings;
+use crate::theme::ThemeKind;
+
+pub const USER_FILE: &str = "config.toml";
+pub const PROJECT_FILE: &str = "fallout-game.toml";
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Settings {
+    pub game: Game,
+    pub display: Display,
+    pub keys: KeyBindings,
+    #[serde(skip)]
+    pub sources: Vec<String>, // layers that were found, lowest first
+}
+
+// [game], anything unset falls back to content.toml / the title menu
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Game {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>, // skip the title, start this board
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub words: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_limit: Option<u64>, // timed mode, seconds per board
+}
+
+// [display]
+#[derive(Clone, Debug, Serialize)]
+pub struct Display {
+    pub theme: ThemeKind,
+    pub animations: bool,
+    pub column_spacing: u16, // gap between board columns
+    pub hex_prefix: String,  // drawn before every address, "0x" for the classic look
+}
+impl Default for Display {
+    fn default() -> Self {
+        Self { theme: ThemeKind::default(), animations: true, column_spacing: 1, hex_prefix: String::new() }
+    }
+}
+
+// What one layer can say, every key optional
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Layer {
+    pub game: Game,
+    pub display: DisplayLayer,
+    pub keys: KeyBindings,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DisplayLayer {
+    pub theme: Option<ThemeKind>,
+    pub animations: Option<bool>,
+    pub column_spacing: Option<u16>,
+    pub hex_prefix: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            game: Game::default(),
+            display: Display::default(),
+            keys: KeyBindings::defaults(),
+            sources: vec!["defaults".to_string()],
+        }
+    }
+}
+
+// the file layers, lowest first: the user file, then the project file
+pub fn layer_files() -> Vec<PathBuf> {
+    vec![crate::storage::config_dir().join(USER_FILE), PathBuf::from(PROJECT_FILE)]
+}
+
+impl Settings {
+    // defaults < each of `files` in order < `flags`
+    pub fn load(files: &[PathBuf], flags: Layer) -> Result<Self> {
+        let mut settings = Settings::default();
+        for path in files {
+            if let Some(layer) = Layer::load(path)? {
+                settings.merge(layer);
+                settings.sources.push(path.display().to_string());
+            }
+        }
+        flags.check().context("command line")?;
+        settings.merge(flags);
+        settings.sources.push("command line".to_string());
+        Ok(settings)
+    }
+
+    fn merge(&mut self, layer: Layer) {
+        let Layer { game, display, keys } = layer;
+        if game.difficulty.is_some() { self.game.difficulty = game.difficulty; }
+        if game.seed.is_some()       { self.game.seed = game.seed; }
+        if game.attempts.is_some()   { self.game.attempts = game.attempts; }
+        if game.geometry.is_some()   { self.game.geometry = game.geometry; }
+        if game.words.is_some()      { self.game.words = game.words; }
+        if game.time_limit.is_some() { self.game.time_limit = game.time_limit; }
+
+        if let Some(theme) = display.theme                   { self.display.theme = theme; }
+        if let Some(animations) = display.animations         { self.display.animations = animations; }
+        if let Some(column_spacing) = display.column_spacing { self.display.column_spacing = column_spacing; }
+        if let Some(hex_prefix) = display.hex_prefix         { self.display.hex_prefix = hex_prefix; }
+
+        self.keys.merge(keys);
+    }
+
+    // --print-config, the merged result with where it came from
+    pub fn dump(&self) -> Result<String> {
+        let mut out = String::new();
+        for source in &self.sources {
+            out.push_str(&format!("# {source}\n"));
+        }
+        out.push('\n');
+        out.push_str(&toml::to_string_pretty(self)?);
+        Ok(out)
+    }
+}
+
+impl Layer {
+    // a missing file is just an empty layer
+    fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() { return Ok(None); }
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let mut layer: Layer = toml::from_str(&contents)
+            .with_context(|| format!("invalid config in {}", path.display()))?;
+        layer.check().with_context(|| format!("invalid config in {}", path.display()))?;
+
+        // word lists are found next to the file that names them
+        if let (Some(words), Some(dir)) = (&mut layer.game.words, path.parent())
+            && words.is_relative()
+        {
+            *words = dir.join(&*words);
+        }
+        Ok(Some(layer))
+    }
+
+    // range checks toml can't do, errors name the key
+    pub fn check(&self) -> Result<()> {
+        if let Some(attempts) = self.game.attempts && !(1..=MAX_ATTEMPTS).contains(&attempts) {
+            bail!("game.attempts = {attempts} must be between 1 and {MAX_ATTEMPTS}");
+        }
+        if let Some(secs) = self.game.time_limit && !(MIN_SECS..=MAX_SECS).contains(&secs) {
+            bail!("game.time_limit = {secs} must be between {MIN_SECS} and {MAX_SECS} seconds");
+        }
+        if let Some(spacing) = self.display.column_spacing && spacing > 4 {
+            bail!("display.column_spacing = {spacing} must be between 0 and 4");
+        }
+        if let Some(prefix) = &self.display.hex_prefix && prefix.chars().count() > 2 {
+            bail!("display.hex_prefix = {prefix:?} can be at most 2 characters");
+        }
+        self.keys.check("keys.")
+    }
+}
+
+#[cfg(test)]
+mod tests;
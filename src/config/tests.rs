@@ -0,0 +1,130 @@
+// src/config/tests.rs
+// Layers from throwaway files: each one overrides only the keys it sets,
+// word lists are found next to the file that names them, bad values are
+// refused by key name and --print-config reads back as the merged result.
+
+use std::path::{Path, PathBuf};
+
+use super::{Layer, Settings};
+use crate::app::Difficulty;
+use crate::theme::ThemeKind;
+
+// a fresh directory for one test's files
+fn dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("fallout-game-config-{name}-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(path: &Path, toml: &str) -> PathBuf {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, toml).unwrap();
+    path.to_path_buf()
+}
+
+// the error loading `toml` as the only file layer, which has to mention `says`
+fn refused(toml: &str, says: &str) {
+    let path = write(&dir("refused").join("config.toml"), toml);
+    match Settings::load(std::slice::from_ref(&path), Layer::default()) {
+        Ok(_) => panic!("{toml:?} should be refused"),
+        Err(e) => {
+            let e = format!("{e:#}");
+            assert!(e.contains(says), "{toml:?}: {e}");
+            assert!(e.contains(&path.display().to_string()), "{toml:?} doesn't name the file: {e}");
+        }
+    }
+}
+
+#[test]
+fn later_layers_win_key_by_key() {
+    let dir = dir("merge");
+    let user = write(&dir.join("user.toml"), r#"
+        [game]
+        difficulty = "expert"
+        seed = 1
+        [display]
+        theme = "amber"
+        animations = false
+        [keys]
+        quit = "x"
+    "#);
+    let project = write(&dir.join("project.toml"), r#"
+        [game]
+        seed = 2
+        [display]
+        theme = "white"
+    "#);
+    let missing = dir.join("missing.toml");
+    let mut flags = Layer::default();
+    flags.game.seed = Some(3);
+
+    let settings = Settings::load(&[user.clone(), missing, project.clone()], flags).unwrap();
+    assert_eq!(settings.game.difficulty, Some(Difficulty::Expert), "only the user file sets it");
+    assert_eq!(settings.game.seed, Some(3), "the command line beats both files");
+    assert_eq!(settings.display.theme, ThemeKind::White, "the project file beats the user file");
+    assert!(!settings.display.animations);
+    assert_eq!(settings.display.column_spacing, 1, "nobody sets it, the default stays");
+    assert_eq!(settings.keys.quit.as_deref(), Some("x"));
+    assert_eq!(settings.keys.down.as_deref(), Some("j"), "unbound keys keep their default");
+    assert_eq!(settings.sources, [
+        "defaults".to_string(),
+        user.display().to_string(),
+        project.display().to_string(),
+        "command line".to_string(),
+    ]);
+}
+
+#[test]
+fn word_lists_are_found_next_to_their_file() {
+    let dir = dir("words");
+    let nested = write(&dir.join("nested").join("fallout-game.toml"), "[game]\nwords = \"lists/words.txt\"");
+    let settings = Settings::load(&[nested], Layer::default()).unwrap();
+    assert_eq!(settings.game.words, Some(dir.join("nested").join("lists/words.txt")));
+
+    let absolute = dir.join("elsewhere.txt");
+    let file = write(&dir.join("absolute.toml"), &format!("[game]\nwords = {:?}", absolute.display().to_string()));
+    let settings = Settings::load(&[file], Layer::default()).unwrap();
+    assert_eq!(settings.game.words, Some(absolute));
+
+    // --words is relative to where the game was started, left alone
+    let mut flags = Layer::default();
+    flags.game.words = Some(PathBuf::from("words.txt"));
+    let settings = Settings::load(&[], flags).unwrap();
+    assert_eq!(settings.game.words, Some(PathBuf::from("words.txt")));
+}
+
+#[test]
+fn bad_values_name_their_key() {
+    refused("[display]\ncolumn_spacing = 9", "display.column_spacing = 9 must be between 0 and 4");
+    refused("[display]\nhex_prefix = \"0x0\"", "display.hex_prefix = \"0x0\" can be at most 2 characters");
+    refused("[game]\nattempts = 0", "game.attempts = 0 must be between 1 and 5");
+    refused("[game]\ntime_limit = 5", "game.time_limit = 5 must be between 10 and 3600 seconds");
+    refused("[keys]\nquit = \"xy\"", "keys.quit = \"xy\" must be a single character");
+    refused("[game]\nlives = 3", "unknown field `lives`");
+    refused("[display]\ntheme = \"pink\"", "unknown variant `pink`");
+
+    // flags are checked too, and say they came from the command line
+    let mut flags = Layer::default();
+    flags.display.column_spacing = Some(9);
+    let e = format!("{:#}", Settings::load(&[], flags).expect_err("spacing 9 should be refused"));
+    assert!(e.starts_with("command line: display.column_spacing = 9"), "{e}");
+}
+
+#[test]
+fn dump_reads_back_as_the_merged_settings() {
+    let dir = dir("dump");
+    let user = write(&dir.join("config.toml"), "[game]\nattempts = 2\n[display]\ntheme = \"amber\"\nhex_prefix = \"0x\"");
+    let settings = Settings::load(std::slice::from_ref(&user), Layer::default()).unwrap();
+    let dump = settings.dump().unwrap();
+    assert!(dump.starts_with(&format!("# defaults\n# {}\n# command line\n", user.display())), "{dump}");
+
+    let layer: Layer = toml::from_str(&dump).expect("the dump should be a valid config file");
+    layer.check().unwrap();
+    assert_eq!(layer.game.attempts, Some(2));
+    assert_eq!(layer.game.seed, None, "unset keys stay out of the dump");
+    assert_eq!(layer.display.theme, Some(ThemeKind::Amber));
+    assert_eq!(layer.display.hex_prefix.as_deref(), Some("0x"));
+    assert_eq!(layer.display.animations, Some(true));
+    assert_eq!(layer.keys.quit.as_deref(), Some("q"));
+}
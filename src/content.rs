@@ -0,0 +1,237 @@
+// src/content.rs
+// Terminal content loaded from assets/content.toml: brands, boot text, status
+// lines, unlocked entries and the lock levels. Loaded once at startup and
+// validated so a bad edit fails with a message instead of a panic mid-game.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::app::{Difficulty, HALF_CHARS, MIN_JUNK_GAP, TOTAL_CELLS};
+use crate::terminal::{Entry, EntryKind};
+
+pub const CONTENT_PATH: &str = "assets/content.toml";
+pub const WORDS_PATH: &str = "assets/tokens.txt";
+
+// most attempts a board can have, perks and run carry-over included
+pub const MAX_ATTEMPTS: u8 = 5;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Content {
+    pub status: StatusLines,
+    pub history: HistoryLines,
+    pub difficulty: Locks,
+    pub brand: Vec<Brand>,
+}
+
+// Header status line for each stage of a hack
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StatusLines {
+    pub password: String, // while guessing
+    pub warning: String,  // one attempt left
+    pub granted: String,  // unlocked
+    pub locked: String,   // out of attempts
+    pub time_low: String,      // timed boards, under clock::LOW_MS left
+    pub time_critical: String, // timed boards, under clock::CRITICAL_MS left
+    pub timeout: String,       // timed boards, out of time
+}
+
+// History panel lines for a bracket pair's reward
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HistoryLines {
+    pub dud_removed: String, // a dud was blanked out
+    pub replenished: String, // attempts refilled
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Locks {
+    pub novice: Lock,
+    pub advanced: Lock,
+    pub expert: Lock,
+    pub master: Lock,
+}
+
+// Board parameters for one lock level
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Lock {
+    pub word_len: usize,
+    pub word_count: usize,
+    pub bracket_pairs: usize,
+    pub attempts: u8,
+    #[serde(default)]
+    pub solvable: bool, // redeal word sets the solver can't always crack within attempts
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Brand {
+    pub id: String,
+    pub title: String,
+    pub boot: Vec<String>,
+    #[serde(rename = "entry", default)]
+    pub entries: Vec<Entry>,
+}
+
+
+impl Content {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {path}"))?;
+        Self::parse(&contents).with_context(|| format!("invalid terminal content in {path}"))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let content: Content = toml::from_str(contents)?;
+        content.validate()?;
+        Ok(content)
+    }
+
+    pub fn lock(&self, difficulty: Difficulty) -> &Lock {
+        match difficulty {
+            Difficulty::Novice   => &self.difficulty.novice,
+            Difficulty::Advanced => &self.difficulty.advanced,
+            Difficulty::Expert   => &self.difficulty.expert,
+            Difficulty::Master   => &self.difficulty.master,
+        }
+    }
+
+    pub fn lock_mut(&mut self, difficulty: Difficulty) -> &mut Lock {
+        match difficulty {
+            Difficulty::Novice   => &mut self.difficulty.novice,
+            Difficulty::Advanced => &mut self.difficulty.advanced,
+            Difficulty::Expert   => &mut self.difficulty.expert,
+            Difficulty::Master   => &mut self.difficulty.master,
+        }
+    }
+
+    // every lock level needs enough words of its length to fill a board
+    pub fn check_words(&self, words: &[String]) -> Result<()> {
+        for d in Difficulty::ALL {
+            let lock = self.lock(d);
+            // repeats only count once, new_word_list drops them
+            let n = words.iter()
+                .filter(|w| w.chars().count() == lock.word_len)
+                .collect::<std::collections::HashSet<_>>()
+                .len();
+            if n < lock.word_count {
+                bail!(
+                    "difficulty.{} needs {} words of {} letters, the list has {n}",
+                    d.name().to_lowercase(), lock.word_count, lock.word_len,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // brand by index, falls back to the first one (validate ensures one exists)
+    pub fn brand(&self, i: usize) -> &Brand {
+        self.brand.get(i).unwrap_or(&self.brand[0])
+    }
+
+    fn validate(&self) -> Result<()> {
+        let lines = [
+            ("status.password", &self.status.password),
+            ("status.warning", &self.status.warning),
+            ("status.granted", &self.status.granted),
+            ("status.locked", &self.status.locked),
+            ("status.time_low", &self.status.time_low),
+            ("status.time_critical", &self.status.time_critical),
+            ("status.timeout", &self.status.timeout),
+            ("history.dud_removed", &self.history.dud_removed),
+            ("history.replenished", &self.history.replenished),
+        ];
+        for (key, line) in lines {
+            if line.trim().is_empty() { bail!("{key} must not be empty"); }
+        }
+
+        for d in Difficulty::ALL {
+            let key = format!("difficulty.{}", d.name().to_lowercase());
+            self.lock(d).validate().with_context(|| key)?;
+        }
+
+        if self.brand.is_empty() { bail!("at least one [[brand]] is required"); }
+        for (i, brand) in self.brand.iter().enumerate() {
+            let key = format!("brand[{i}] ({})", brand.id);
+            brand.validate().with_context(|| key)?;
+            if self.brand[..i].iter().any(|b| b.id == brand.id) {
+                bail!("brand[{i}]: duplicate id {:?}", brand.id);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Lock {
+    pub fn validate(&self) -> Result<()> {
+        // the default tokens.txt only has words of 4 to 12 letters
+        if !(4..=12).contains(&self.word_len) {
+            bail!("word_len = {} must be between 4 and 12", self.word_len);
+        }
+        if self.word_count < 2 {
+            bail!("word_count = {} needs at least a password and one dud", self.word_count);
+        }
+        // the bigger half of the words, with a junk gap either side of each
+        let half = self.word_count.div_ceil(2);
+        if half * self.word_len + (half + 1) * MIN_JUNK_GAP > HALF_CHARS {
+            bail!("{} words of {} letters don't fit on the board", self.word_count, self.word_len);
+        }
+        // every word needs a line of its own to be picked, and 15 to a half is
+        // as many as even the shortest words are sure to get
+        if half >= TOTAL_CELLS / 2 {
+            bail!("word_count = {} is more words than the board has lines for", self.word_count);
+        }
+        if self.bracket_pairs > TOTAL_CELLS {
+            bail!("bracket_pairs = {} is more than the board's {TOTAL_CELLS} lines", self.bracket_pairs);
+        }
+        if !(1..=MAX_ATTEMPTS).contains(&self.attempts) {
+            bail!("attempts = {} must be between 1 and {MAX_ATTEMPTS}", self.attempts);
+        }
+        Ok(())
+    }
+}
+
+impl Brand {
+    fn validate(&self) -> Result<()> {
+        if self.id.trim().is_empty() { bail!("id must not be empty"); }
+        if self.title.trim().is_empty() { bail!("title must not be empty"); }
+        if self.boot.is_empty() { bail!("boot must have at least one line"); }
+        if self.entries.is_empty() { bail!("at least one [[brand.entry]] is required"); }
+
+        for (i, entry) in self.entries.iter().enumerate() {
+            if entry.title.trim().is_empty() { bail!("entry[{i}]: title must not be empty"); }
+            match &entry.kind {
+                EntryKind::Text { body } if body.trim().is_empty() => {
+                    bail!("entry[{i}] ({}): body must not be empty", entry.title);
+                }
+                EntryKind::Toggle { on, off } if on.trim().is_empty() || off.trim().is_empty() => {
+                    bail!("entry[{i}] ({}): toggle needs both on and off lines", entry.title);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+
+// one word per line, blank lines skipped. letters only so the board junk
+// (punctuation) can never be mistaken for part of a word
+pub fn load_words(path: &Path) -> Result<Vec<String>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let mut words = Vec::new();
+    for (i, line) in contents.lines().enumerate() {
+        let word = line.trim();
+        if word.is_empty() { continue; }
+        if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+            bail!("{}:{}: {word:?} must be letters only", path.display(), i + 1);
+        }
+        words.push(word.to_ascii_lowercase());
+    }
+    Ok(words)
+}
@@ -0,0 +1,35 @@
+// src/hint.rs
+// Paid hints, backed by the solver. Each one costs an attempt and a board
+// only allows HINT_BUDGET of them. Daily boards don't allow any.
+
+pub const HINT_BUDGET: usize = 3; // hints per board
+pub const HINT_COST: u8 = 1;      // attempts per hint
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Hint {
+    Eliminated, // dim every word the likeness results have ruled out
+    BestGuess,  // the solver's next guess
+    Letter,     // one letter of the password and where it goes
+}
+impl Hint {
+    pub const ALL: [Hint; 3] = [Hint::Eliminated, Hint::BestGuess, Hint::Letter];
+
+    pub fn key(self) -> char {
+        match self {
+            Hint::Eliminated => '1',
+            Hint::BestGuess  => '2',
+            Hint::Letter     => '3',
+        }
+    }
+    pub fn from_key(c: char) -> Option<Hint> {
+        Hint::ALL.into_iter().find(|h| h.key() == c)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Hint::Eliminated => "Mark eliminated words",
+            Hint::BestGuess  => "Suggest a guess",
+            Hint::Letter     => "Reveal a letter",
+        }
+    }
+}
@@ -1,9 +1,11 @@
 // src/input.rs
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
-use ratatui::Frame;
-use crate::app::{App, Focus, Header, TableModel};
+use crate::app::{App, Focus, Screen};
+use crate::hint::Hint;
+use crate::profile::{Perk, SKILL_STEP};
+use crate::title::{MenuItem, DAILY_DIFFICULTY};
 
 
 // Return 'true' if the app should quit, 'false' otherwise.
@@ -14,12 +16,46 @@ pub fn handle_key(key: KeyEvent, app: &mut App) -> bool {
     let code = key.code;
     let mods = key.modifiers;
 
+    match app.screen {
+        Screen::Title    => { return handle_title_key(key, app); }
+        Screen::Terminal => { return handle_terminal_key(key, app); }
+        Screen::Stats    => { return handle_stats_key(key, app); }
+        Screen::Profile  => { return handle_profile_key(key, app); }
+        Screen::Board    => {}
+    }
+
+    // hint popup swallows keys while it's open
+    if app.show_hints {
+        match code {
+            Char('q') => { return true; }
+            Char(c) => {
+                if let Some(hint) = Hint::from_key(c) { app.use_hint(hint); }
+            }
+            _ => {}
+        }
+        app.show_hints = false;
+        return false;
+    }
+
     match (code, mods) {
         // quit
         (Char('q'), _) => { return true; } // signal quit
 
         (Char('?'), _) => { app.show_help = !app.show_help; }
+        (Char('H'), _) if !app.game_over => { app.show_hints = true; }
+        (Char('c'), _) => {
+            app.toggle_tracker();
+            app.debug.push(format!("tracker={}", app.show_tracker));
+        }
         (Char('\\'), _) => { app.show_debug = !app.show_debug; }
+        (Char('a'), _) => {
+            app.toggle_animations();
+            app.debug.push(format!("animations={}", app.animations));
+        }
+        (Char('t'), _) => {
+            app.theme.next();
+            app.debug.push(format!("theme={}", app.theme.name()));
+        }
 
         // focus change with Ctrl
         (Tab, _) => { app.focus.focus_next_horizontal(); }
@@ -63,13 +99,15 @@ pub fn handle_key(key: KeyEvent, app: &mut App) -> bool {
 
         (Enter, _ ) => { 
             match app.focus { 
-                Focus::Side => { 
-                    if !app.input.trim().is_empty() { 
-                        app.items.push(app.input.trim().to_string()); 
-                        app.input.clear();
-                    }
+                Focus::Side if !app.input.trim().is_empty() => { 
+                    app.items.push(app.input.trim().to_string()); 
+                    app.input.clear();
                 }
                 Focus::Main => { 
+                    // a bracket pair under the cursor takes priority, then words
+                    if app.use_brackets() { return false; }
+                    if app.submit_guess() { return false; }
+
                     // need to get the currently hovered cell
                     let word = app.word_at_coordinates(app.col_state, app.state.selected());
 
@@ -83,7 +121,8 @@ pub fn handle_key(key: KeyEvent, app: &mut App) -> bool {
         }
 
 
-        //// dialog (needs to got at bottom)
+        //// dialog (needs to got at bottom), not wired up yet: most letters
+        //// are board and system keys, see DESIGN.md
         //(Char(c), m) if !m.contains(KeyModifiers::CONTROL) && !m.contains(KeyModifiers::ALT) => {
             //app.input.push(c);
         //}
@@ -101,14 +140,101 @@ pub fn handle_key(key: KeyEvent, app: &mut App) -> bool {
 }
 
 
+// Title screen keys: any key skips the boot text, then the menu takes over
+fn handle_title_key(key: KeyEvent, app: &mut App) -> bool {
+    use KeyCode::*;
+
+    if key.code == Char('q') { return true; }
+    if !app.title.booted() {
+        app.title.skip_boot();
+        return false;
+    }
+
+    match key.code {
+        Up   | Char('k') => { app.title.menu_up(); }
+        Down | Char('j') => { app.title.menu_down(); }
+        Enter => match app.title.selected_item() {
+            MenuItem::Play(d) => { app.start_board(d, false); }
+            MenuItem::Daily   => { app.start_board(DAILY_DIFFICULTY, true); }
+            MenuItem::Run     => { app.start_run(); }
+            MenuItem::Profile => { app.screen = Screen::Profile; }
+            MenuItem::Stats   => { app.screen = Screen::Stats; }
+        },
+        Char('t') => { app.theme.next(); }
+        Char('a') => { app.toggle_animations(); }
+        _ => {}
+    }
+    false
+}
+
+
+// Unlocked terminal keys, same j/k + Enter as the board
+fn handle_terminal_key(key: KeyEvent, app: &mut App) -> bool {
+    use KeyCode::*;
+
+    match key.code {
+        Char('q') => { return true; }
+        Up   | Char('k') => { app.terminal.up(); }
+        Down | Char('j') => { app.terminal.down(); }
+        Enter => { app.terminal.activate(); }
+        Esc | Backspace | Char('h') => { app.terminal.back(); }
+        Char('?')  => { app.show_help = !app.show_help; }
+        Char('\\') => { app.show_debug = !app.show_debug; }
+        Char('t') => { app.theme.next(); }
+        _ => {}
+    }
+    false
+}
+
+
+// Stats screen is read-only, anything but q goes back to the title menu
+fn handle_stats_key(key: KeyEvent, app: &mut App) -> bool {
+    if key.code == KeyCode::Char('q') { return true; }
+    app.screen = Screen::Title;
+    false
+}
+
+
+// Profile screen: a perk key picks it if there's a free slot, anything
+// else but q goes back to the title menu
+fn handle_profile_key(key: KeyEvent, app: &mut App) -> bool {
+    let perk = match key.code {
+        KeyCode::Char('q') => { return true; }
+        KeyCode::Char(c) => Perk::from_key(c),
+        _ => None,
+    };
+    match perk {
+        Some(perk) => {
+            app.profile_note = if app.profile.pick(perk) {
+                app.profile_dirty = true;
+                app.debug.push(format!("profile pick={perk:?}"));
+                None
+            } else if app.profile.has(perk) {
+                Some(format!("{} is already yours.", perk.label()))
+            } else {
+                Some(format!("No perk to pick yet, one comes with every {SKILL_STEP} Science."))
+            };
+        }
+        None => {
+            app.profile_note = None;
+            app.screen = Screen::Title;
+        }
+    }
+    false
+}
+
+
 // Mouse handler takes a MouseEvent, not Event::Mouse
-pub fn handle_mouse(me: MouseEvent, app: &mut App, side_area: Rect) {
+pub fn handle_mouse(me: MouseEvent, _app: &mut App, _side_area: Rect) {
     match me.kind {
         MouseEventKind::Moved | MouseEventKind::Down(_) => {
-            let x = me.column;
-            let y = me.row;
+            let _x = me.column;
+            let _y = me.row;
             // is mouse over sidebar?
         }
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests;
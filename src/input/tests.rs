@@ -0,0 +1,327 @@
+// src/input/tests.rs
+// Scripted key presses through handle_key against a seeded board, checking
+// what the player would see change: focus, cursor, attempts, history,
+// popups, game over and quitting. Typing into the Side input isn't wired up
+// (see DESIGN.md), only Enter on a junk cell fills it.
+
+use crossterm::event::KeyCode::{self, BackTab, Down, Enter, Left, Right, Tab, Up};
+
+use crate::app::{App, Difficulty, Focus, Screen, TableModel};
+use crate::testing::{self, keys, press, walk_to};
+use crate::title::DAILY_DIFFICULTY;
+
+const SEED: u64 = 47;
+
+fn board() -> App {
+    testing::board(SEED, Difficulty::Novice)
+}
+
+fn cursor(app: &App) -> (Option<usize>, usize) {
+    (app.state.selected(), app.col_state)
+}
+
+fn has_brackets(app: &App, row: usize, col: usize) -> bool {
+    !TableModel::bracket_pairs(&app.table_contents[row][col]).is_empty()
+}
+
+// keys that put the cursor on the first cell with a bracket pair and press Enter
+fn bracket_keys(app: &App) -> Vec<KeyCode> {
+    let rows = app.table_contents.len();
+    let (row, col) = (0..rows)
+        .flat_map(|row| [(row, 1), (row, 3)])
+        .find(|&(row, col)| has_brackets(app, row, col))
+        .expect("the board should have a bracket pair");
+    let mut codes = walk_to(app, row, col);
+    codes.push(Enter);
+    codes
+}
+
+// keys that put the cursor on `word` (a cell without a bracket pair, so
+// Enter guesses instead of using the pair) and press Enter
+fn guess_keys(app: &App, word: &str) -> Vec<KeyCode> {
+    let &(row, col) = testing::word_cells(&app.main, word)
+        .iter()
+        .find(|&&(row, col)| !has_brackets(app, row, col))
+        .unwrap_or_else(|| panic!("{word} has no cell without brackets"));
+    let mut codes = walk_to(app, row, col);
+    codes.push(Enter);
+    codes
+}
+
+fn guess(app: &mut App, word: &str) -> bool {
+    let codes = guess_keys(app, word);
+    press(app, &codes)
+}
+
+
+#[test]
+fn focus_cycles_between_panels() {
+    let mut app = board();
+    assert_eq!(app.focus, Focus::Main);
+
+    let steps = [
+        (Tab, Focus::Side),
+        (Tab, Focus::Main),
+        (BackTab, Focus::Side),
+        (Left, Focus::Main),
+        (Right, Focus::Side),
+        (Up, Focus::Head),
+        (Left, Focus::Head), // the header has nothing beside it
+        (Down, Focus::Main),
+    ];
+    for (code, focus) in steps {
+        assert!(!press(&mut app, &[code]));
+        assert_eq!(app.focus, focus, "after {code:?}");
+    }
+}
+
+#[test]
+fn cursor_moves_and_wraps() {
+    let mut app = board();
+    let rows = app.table_contents.len();
+    let last_col = app.ts.columns - 1;
+    assert_eq!(cursor(&app), (Some(0), 0));
+
+    press(&mut app, &keys("k"));
+    assert_eq!(cursor(&app), (Some(rows - 1), 0));
+    press(&mut app, &keys("j"));
+    assert_eq!(cursor(&app), (Some(0), 0));
+    press(&mut app, &keys("h"));
+    assert_eq!(cursor(&app), (Some(0), last_col));
+    press(&mut app, &keys("ljjl"));
+    assert_eq!(cursor(&app), (Some(2), 1));
+}
+
+#[test]
+fn cursor_stays_put_off_the_board() {
+    let mut app = board();
+    press(&mut app, &keys("\tjjl"));
+    assert_eq!(app.focus, Focus::Side);
+    assert_eq!(cursor(&app), (Some(0), 0));
+}
+
+#[test]
+fn enter_on_a_dud_costs_an_attempt() {
+    let mut app = board();
+    let dud = testing::duds(&app.main)[0].clone();
+    let attempts = app.header.health_i;
+
+    assert!(!guess(&mut app, &dud));
+    let likeness = TableModel::likeness(&dud, &app.main.password);
+    assert_eq!(app.header.health_i, attempts - 1);
+    assert_eq!(app.main.guesses, vec![(dud.clone(), likeness)]);
+    assert_eq!(app.items[app.items.len() - 3..], [
+        format!(">{dud}"),
+        ">Entry denied.".to_string(),
+        format!(">Likeness={likeness}"),
+    ]);
+    assert!(!app.game_over);
+}
+
+#[test]
+fn enter_on_the_password_unlocks() {
+    let mut app = board();
+    let password = app.main.password.clone();
+
+    assert!(!guess(&mut app, &password));
+    assert_eq!(app.screen, Screen::Terminal);
+    assert!(app.items.contains(&">Exact match!".to_string()));
+    assert_eq!(app.stats.wins, 1);
+}
+
+#[test]
+fn running_out_of_attempts_ends_the_game() {
+    let mut app = board();
+    let attempts = usize::from(app.header.health_i);
+    for dud in testing::duds(&app.main).iter().take(attempts) {
+        assert!(!app.game_over, "locked out before guessing {dud}");
+        guess(&mut app, dud);
+    }
+    assert!(app.game_over);
+    assert_eq!(app.header.health_i, 0);
+    assert_eq!(app.screen, Screen::Board);
+
+    // the board is dead: no more guesses, no hint popup
+    let password = app.main.password.clone();
+    guess(&mut app, &password);
+    assert_eq!(app.main.guesses.len(), attempts);
+    press(&mut app, &keys("H"));
+    assert!(!app.show_hints);
+
+    // nor brackets, which would refill attempts under the popup
+    let (items, codes) = (app.items.clone(), bracket_keys(&app));
+    press(&mut app, &codes);
+    assert_eq!(app.main.brackets_used, 0);
+    assert_eq!(app.header.health_i, 0);
+    assert_eq!(app.header.status, app.content.status.locked);
+    assert_eq!(app.items, items);
+}
+
+#[test]
+fn brackets_pay_out_with_the_content_lines() {
+    let mut app = board();
+    // nothing lost yet, so the pair always blanks a dud
+    let codes = bracket_keys(&app);
+    press(&mut app, &codes);
+    assert_eq!(app.main.brackets_used, 1);
+    assert_eq!(app.main.removed_duds.len(), 1);
+    assert_eq!(app.items.last(), Some(&app.content.history.dud_removed));
+}
+
+#[test]
+fn health_bar_has_a_block_per_attempt() {
+    let mut app = board();
+    for (attempts, bar) in [(0, ""), (1, "█"), (3, "█ █ █"), (5, "█ █ █ █ █")] {
+        app.header.health_i = attempts;
+        assert_eq!(app.header.new_health_bar(), bar);
+    }
+}
+
+#[test]
+fn enter_on_junk_copies_it_to_the_side_panel() {
+    let mut app = board();
+    let rows = app.table_contents.len();
+    let (row, col) = (0..rows)
+        .flat_map(|row| [(row, 1), (row, 3)])
+        .find(|&(row, col)| {
+            let cell = TableModel::cell_index(row, col).unwrap();
+            app.main.word_at_cell(cell).is_none() && !has_brackets(&app, row, col)
+        })
+        .expect("some cell should be only junk");
+    let junk = app.table_contents[row][col].clone();
+
+    let mut codes = walk_to(&app, row, col);
+    codes.push(Enter);
+    press(&mut app, &codes);
+    assert_eq!(app.input, junk);
+    assert!(app.main.guesses.is_empty());
+
+    // Enter on the side panel moves the input into the history
+    let history = app.items.len();
+    press(&mut app, &keys("\t\n"));
+    assert_eq!(app.items.len(), history + 1);
+    assert_eq!(app.items.last(), Some(&junk.trim().to_string()));
+    assert!(app.input.is_empty());
+
+    // and an empty input adds nothing
+    press(&mut app, &keys("\n"));
+    assert_eq!(app.items.len(), history + 1);
+}
+
+#[test]
+fn help_and_debug_toggle() {
+    let mut app = board();
+    press(&mut app, &keys("?"));
+    assert!(app.show_help);
+    press(&mut app, &keys("?"));
+    assert!(!app.show_help);
+
+    press(&mut app, &keys("\\"));
+    assert!(app.show_debug);
+    press(&mut app, &keys("\\"));
+    assert!(!app.show_debug);
+}
+
+#[test]
+fn hint_popup_swallows_the_next_key() {
+    let mut app = board();
+    press(&mut app, &keys("H"));
+    assert!(app.show_hints);
+    press(&mut app, &keys("j"));
+    assert!(!app.show_hints);
+    assert_eq!(cursor(&app), (Some(0), 0));
+}
+
+#[test]
+fn q_quits_from_everywhere() {
+    let mut app = testing::app(SEED);
+    assert_eq!(app.screen, Screen::Title);
+    assert!(press(&mut app, &keys("q")));
+
+    let mut app = board();
+    assert!(press(&mut app, &keys("q")));
+
+    // the hint popup doesn't get to eat it
+    let mut app = board();
+    assert!(press(&mut app, &keys("Hq")));
+
+    // nothing after the quit is handled
+    let mut app = board();
+    assert!(press(&mut app, &keys("q?")));
+    assert!(!app.show_help);
+}
+
+#[test]
+fn title_menu_starts_a_board() {
+    let mut app = testing::app(SEED);
+    press(&mut app, &keys(" ")); // skips the boot text
+    assert_eq!(app.screen, Screen::Title);
+    press(&mut app, &keys("\n"));
+    assert_eq!(app.screen, Screen::Board);
+    assert_eq!(app.main.difficulty, Difficulty::Novice);
+}
+
+#[test]
+fn title_menu_takes_the_first_key_without_animations() {
+    fastrand::seed(SEED);
+    let content = crate::content::Content::load(crate::content::CONTENT_PATH).unwrap();
+    let mut options = testing::options();
+    options.settings.display.animations = false;
+    let mut app = App::new(content, &options).unwrap();
+    assert!(app.title.booted());
+
+    press(&mut app, &keys("j"));
+    assert_eq!(app.title.selected, 1);
+}
+
+#[test]
+fn status_changes_type_out_again() {
+    let mut app = board();
+    app.toggle_animations();
+    let title = app.header.title.chars().count();
+    let total = app.header_chars();
+    app.header_fx.finish(total);
+
+    let attempts = usize::from(app.header.health_i);
+    for dud in testing::duds(&app.main).iter().take(attempts - 1) {
+        guess(&mut app, dud);
+    }
+    assert_eq!(app.header.status, app.content.status.warning);
+    assert_eq!(app.header_fx.shown(), title);
+    app.on_tick();
+    assert_eq!(app.header_fx.shown(), title + 1);
+}
+
+#[test]
+fn tracker_lists_and_dims_ruled_out_words() {
+    let mut app = board();
+    let dud = testing::duds(&app.main)[0].clone();
+    guess(&mut app, &dud);
+    assert!(!app.main.eliminated_words().is_empty());
+    assert!(!app.dim_eliminated());
+
+    press(&mut app, &keys("c"));
+    assert!(app.show_tracker);
+    assert!(app.dim_eliminated());
+    press(&mut app, &keys("c"));
+    assert!(!app.dim_eliminated());
+
+    // the hint dims them for the rest of the board, tracker open or not
+    let attempts = app.header.health_i;
+    press(&mut app, &keys("H1"));
+    assert!(app.dim_eliminated());
+    assert_eq!(app.header.health_i, attempts - 1);
+    press(&mut app, &keys("cc"));
+    assert!(!app.show_tracker);
+    assert!(app.dim_eliminated());
+}
+
+#[test]
+fn tracker_works_on_daily_boards() {
+    let mut app = testing::app(38);
+    if app.animations { app.toggle_animations(); }
+    assert!(app.start_board(DAILY_DIFFICULTY, true));
+    press(&mut app, &keys("c"));
+    assert!(app.show_tracker);
+    assert!(app.dim_eliminated());
+}
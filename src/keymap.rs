@@ -0,0 +1,229 @@
+// src/keymap.rs
+// Rebindable letter keys, from the config [keys] table or --keymap. The
+// handlers in input.rs only know the default letters, so the run loop
+// translates a rebound key back to its default before anything sees it
+// (and before it's recorded, so replays don't depend on the keymap).
+
+use anyhow::{bail, Context, Result};
+use crossterm::event::{Event, KeyCode};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::hint::Hint;
+use crate::profile::Perk;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Action {
+    Quit,
+    Help,
+    Hints,
+    Tracker,
+    Debug,
+    Animations,
+    Theme,
+    Left,
+    Down,
+    Up,
+    Right,
+}
+impl Action {
+    pub const ALL: [Action; 11] = [
+        Action::Quit,
+        Action::Help,
+        Action::Hints,
+        Action::Tracker,
+        Action::Debug,
+        Action::Animations,
+        Action::Theme,
+        Action::Left,
+        Action::Down,
+        Action::Up,
+        Action::Right,
+    ];
+
+    // key name in a keymap file / [keys] table
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Quit       => "quit",
+            Action::Help       => "help",
+            Action::Hints      => "hints",
+            Action::Tracker    => "tracker",
+            Action::Debug      => "debug",
+            Action::Animations => "animations",
+            Action::Theme      => "theme",
+            Action::Left       => "left",
+            Action::Down       => "down",
+            Action::Up         => "up",
+            Action::Right      => "right",
+        }
+    }
+
+    // the letter input.rs matches on
+    pub fn default_key(self) -> char {
+        match self {
+            Action::Quit       => 'q',
+            Action::Help       => '?',
+            Action::Hints      => 'H',
+            Action::Tracker    => 'c',
+            Action::Debug      => '\\',
+            Action::Animations => 'a',
+            Action::Theme      => 't',
+            Action::Left       => 'h',
+            Action::Down       => 'j',
+            Action::Up         => 'k',
+            Action::Right      => 'l',
+        }
+    }
+}
+
+/* key bindings as written in a keymap file or a config [keys] table, every
+*  key optional, e.g. `quit = "x"`. layers merge key by key
+*/
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyBindings {
+    #[serde(skip_serializing_if = "Option::is_none")] pub quit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub help: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub hints: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub tracker: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub debug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub animations: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub theme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub left: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub down: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub up: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")] pub right: Option<String>,
+}
+impl KeyBindings {
+    // every action on its default letter, the bottom config layer
+    pub fn defaults() -> Self {
+        let mut bindings = Self::default();
+        for action in Action::ALL {
+            *bindings.get_mut(action) = Some(action.default_key().to_string());
+        }
+        bindings
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let bindings: Self = toml::from_str(&contents)
+            .with_context(|| format!("invalid keymap in {}", path.display()))?;
+        bindings.check("").with_context(|| format!("invalid keymap in {}", path.display()))?;
+        Ok(bindings)
+    }
+
+    fn get(&self, action: Action) -> Option<&str> {
+        match action {
+            Action::Quit       => self.quit.as_deref(),
+            Action::Help       => self.help.as_deref(),
+            Action::Hints      => self.hints.as_deref(),
+            Action::Tracker    => self.tracker.as_deref(),
+            Action::Debug      => self.debug.as_deref(),
+            Action::Animations => self.animations.as_deref(),
+            Action::Theme      => self.theme.as_deref(),
+            Action::Left       => self.left.as_deref(),
+            Action::Down       => self.down.as_deref(),
+            Action::Up         => self.up.as_deref(),
+            Action::Right      => self.right.as_deref(),
+        }
+    }
+    fn get_mut(&mut self, action: Action) -> &mut Option<String> {
+        match action {
+            Action::Quit       => &mut self.quit,
+            Action::Help       => &mut self.help,
+            Action::Hints      => &mut self.hints,
+            Action::Tracker    => &mut self.tracker,
+            Action::Debug      => &mut self.debug,
+            Action::Animations => &mut self.animations,
+            Action::Theme      => &mut self.theme,
+            Action::Left       => &mut self.left,
+            Action::Down       => &mut self.down,
+            Action::Up         => &mut self.up,
+            Action::Right      => &mut self.right,
+        }
+    }
+
+    // keys set in `other` win
+    pub fn merge(&mut self, mut other: KeyBindings) {
+        for action in Action::ALL {
+            if let Some(key) = other.get_mut(action).take() {
+                *self.get_mut(action) = Some(key);
+            }
+        }
+    }
+
+    // every binding is one visible character, `prefix` names the table in errors
+    pub fn check(&self, prefix: &str) -> Result<()> {
+        for action in Action::ALL {
+            if let Some(value) = self.get(action) {
+                single_char(value)
+                    .with_context(|| format!("{prefix}{} = {value:?} must be a single character", action.name()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn single_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_whitespace() => Some(c),
+        _ => None,
+    }
+}
+
+// keys input.rs matches that can't be rebound
+fn fixed_keys() -> impl Iterator<Item = char> {
+    Hint::ALL.map(Hint::key).into_iter().chain(Perk::ALL.map(Perk::key))
+}
+
+// (action, bound key), same order as Action::ALL
+#[derive(Clone, Debug)]
+pub struct Keymap {
+    keys: Vec<(Action, char)>,
+}
+impl Default for Keymap {
+    fn default() -> Self {
+        Self { keys: Action::ALL.iter().map(|a| (*a, a.default_key())).collect() }
+    }
+}
+impl Keymap {
+    // bindings that are missing or malformed keep their default letter
+    pub fn new(bindings: &KeyBindings) -> Result<Self> {
+        let mut keymap = Keymap::default();
+        for (action, key) in keymap.keys.iter_mut() {
+            if let Some(c) = bindings.get(*action).and_then(single_char) {
+                *key = c;
+            }
+        }
+        for (i, (action, key)) in keymap.keys.iter().enumerate() {
+            if let Some((other, _)) = keymap.keys[..i].iter().find(|(_, k)| k == key) {
+                bail!("keys.{} = {key:?} is already bound to keys.{}", action.name(), other.name());
+            }
+            // translate would turn them into the action on every screen
+            if fixed_keys().any(|k| k == *key) {
+                bail!("keys.{} = {key:?} is taken by the hint and perk keys", action.name());
+            }
+        }
+        Ok(keymap)
+    }
+
+    /* rebound key -> the default letter for its action. a default letter
+    *  whose action moved elsewhere is dropped, anything else passes through
+    */
+    pub fn translate(&self, event: Event) -> Option<Event> {
+        let Event::Key(mut key) = event else { return Some(event); };
+        let KeyCode::Char(c) = key.code else { return Some(Event::Key(key)); };
+
+        if let Some((action, _)) = self.keys.iter().find(|(_, k)| *k == c) {
+            key.code = KeyCode::Char(action.default_key());
+        } else if Action::ALL.iter().any(|a| a.default_key() == c) {
+            return None;
+        }
+        Some(Event::Key(key))
+    }
+}
+
+#[cfg(test)]
+mod tests;
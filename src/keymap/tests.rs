@@ -0,0 +1,53 @@
+// src/keymap/tests.rs
+// Bindings that would leave two things on one key are refused, swaps and
+// free letters go through and translate back to the default letters.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use super::{KeyBindings, Keymap};
+
+fn bindings(toml: &str) -> KeyBindings {
+    toml::from_str(toml).expect("test bindings should parse")
+}
+
+fn refused(toml: &str, says: &str) {
+    match Keymap::new(&bindings(toml)) {
+        Ok(_) => panic!("{toml:?} should be refused"),
+        Err(e) => assert!(format!("{e:#}").contains(says), "{toml:?}: {e:#}"),
+    }
+}
+
+fn translate(keymap: &Keymap, c: char) -> Option<KeyCode> {
+    let event = Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    match keymap.translate(event)? {
+        Event::Key(key) => Some(key.code),
+        _ => None,
+    }
+}
+
+#[test]
+fn hint_and_perk_keys_are_fixed() {
+    for key in ["1", "2", "3"] {
+        refused(&format!("theme = {key:?}"), "taken by the hint and perk keys");
+    }
+}
+
+#[test]
+fn two_actions_cant_share_a_key() {
+    // j is still down's, whatever quit wants
+    refused("quit = \"j\"", "already bound to keys.quit");
+    refused("tracker = \"a\"", "already bound to keys.tracker");
+    refused("left = \"x\"\nright = \"x\"", "already bound to keys.left");
+}
+
+#[test]
+fn swaps_and_free_letters_translate() {
+    let keymap = Keymap::new(&bindings("quit = \"x\"\ndown = \"q\"\nup = \"w\"")).unwrap();
+    assert_eq!(translate(&keymap, 'x'), Some(KeyCode::Char('q')));
+    assert_eq!(translate(&keymap, 'q'), Some(KeyCode::Char('j')));
+    assert_eq!(translate(&keymap, 'w'), Some(KeyCode::Char('k')));
+    // k moved to w, so k itself does nothing now
+    assert_eq!(translate(&keymap, 'k'), None);
+    // keys nothing is bound to pass straight through
+    assert_eq!(translate(&keymap, '1'), Some(KeyCode::Char('1')));
+}
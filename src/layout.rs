@@ -0,0 +1,99 @@
+// src/layout.rs
+// Board screen arrangement by terminal size. The board table is never
+// squeezed: its exact size comes from ui::board_size and the arrangement
+// that fits around it is picked here, or none if the terminal is too small.
+//
+//   Wide      header over board | side, centered at its natural size
+//   Standard  header over board | side, filling the terminal
+//   Compact   header, board and side stacked, for narrow terminals
+
+use ratatui::layout::Rect;
+
+pub const HEADER_HEIGHT: u16 = 6;      // 4 lines + border
+pub const SLIM_HEADER_HEIGHT: u16 = 4; // title and status share a line, no spacer
+pub const SIDE_MIN: u16 = 24;          // side panel, narrowest that still reads
+pub const SIDE_MAX: u16 = 60;          // side panel, widest before it's just space
+pub const STACKED_SIDE_MIN: u16 = 5;   // border + 2 history lines + input
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LayoutKind {
+    Compact,
+    Standard,
+    Wide,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoardLayout {
+    pub kind: LayoutKind,
+    pub header: Rect,
+    pub slim_header: bool,
+    pub main: Rect,
+    pub side: Rect,
+    pub history: Rect, // inside the side border
+    pub input: Rect,   // last line inside the side border
+}
+
+/* arrangement for a board table of `board_w` x `board_h` (borders included),
+*  Err holds the smallest size that would work
+*/
+pub fn board_layout(area: Rect, board_w: u16, board_h: u16) -> Result<BoardLayout, (u16, u16)> {
+    let fits = |w: u16, h: u16| area.width >= w && area.height >= h;
+    let header_for = |body: u16| {
+        if area.height >= HEADER_HEIGHT + body { HEADER_HEIGHT } else { SLIM_HEADER_HEIGHT }
+    };
+
+    if fits(board_w + SIDE_MAX, HEADER_HEIGHT + board_h) {
+        let width = board_w + SIDE_MAX;
+        let height = HEADER_HEIGHT + board_h;
+        let block = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+        return Ok(side_by_side(LayoutKind::Wide, block, HEADER_HEIGHT, board_w));
+    }
+    if fits(board_w + SIDE_MIN, SLIM_HEADER_HEIGHT + board_h) {
+        return Ok(side_by_side(LayoutKind::Standard, area, header_for(board_h), board_w));
+    }
+    if fits(board_w, SLIM_HEADER_HEIGHT + board_h + STACKED_SIDE_MIN) {
+        let header_h = header_for(board_h + STACKED_SIDE_MIN);
+        let header = Rect { height: header_h, ..area };
+        let main = Rect { y: area.y + header_h, height: board_h, ..area };
+        let side = Rect {
+            y: main.y + board_h,
+            height: area.height - header_h - board_h,
+            ..area
+        };
+        return Ok(with_side(LayoutKind::Compact, header, main, side));
+    }
+    Err((board_w + SIDE_MIN, SLIM_HEADER_HEIGHT + board_h))
+}
+
+fn side_by_side(kind: LayoutKind, block: Rect, header_h: u16, board_w: u16) -> BoardLayout {
+    let header = Rect { height: header_h, ..block };
+    let body = Rect { y: block.y + header_h, height: block.height - header_h, ..block };
+    let main = Rect { width: board_w, ..body };
+    let side = Rect { x: body.x + board_w, width: body.width - board_w, ..body };
+    with_side(kind, header, main, side)
+}
+
+fn with_side(kind: LayoutKind, header: Rect, main: Rect, side: Rect) -> BoardLayout {
+    let inner = Rect {
+        x: side.x + 1,
+        y: side.y + 1,
+        width: side.width.saturating_sub(2),
+        height: side.height.saturating_sub(2),
+    };
+    let history = Rect { height: inner.height.saturating_sub(1), ..inner };
+    let input = Rect { y: inner.y + history.height, height: inner.height.min(1), ..inner };
+    BoardLayout {
+        kind,
+        header,
+        slim_header: header.height < HEADER_HEIGHT,
+        main,
+        side,
+        history,
+        input,
+    }
+}
@@ -1,4 +1,4 @@
-use color_eyre::Result;
+use color_eyre::{eyre::eyre, Result};
 use crossterm::{
     event::{EnableMouseCapture, DisableMouseCapture},
     execute
@@ -9,13 +9,82 @@ use std::io::stdout;
 mod app;
 mod ui;
 mod input;
+mod keymap;
+mod layout;
 mod assets;
+mod bench;
+mod campaign;
+mod cli;
+mod clock;
+mod config;
+mod content;
+mod hint;
+mod anim;
+mod terminal;
+mod replay;
+mod save;
+mod profile;
+mod solver;
+mod stats;
+mod storage;
+mod theme;
+mod title;
+
+#[cfg(test)]
+mod testing;
 
 fn main() -> Result<()> {
     color_eyre::install()?;                          // init color_eyre
+    // before the tui so errors stay readable
+    let mut options = cli::parse(std::env::args().skip(1)).map_err(|e| eyre!("{e:#}"))?;
+    match &options.mode {                            // neither needs the assets, work from any directory
+        cli::Mode::Help => {
+            print!("{}", cli::USAGE);
+            return Ok(());
+        }
+        cli::Mode::PrintConfig => {
+            print!("{}", options.settings.dump().map_err(|e| eyre!("{e:#}"))?);
+            return Ok(());
+        }
+        _ => {}
+    }
+    let mut playback = None;
+    if let cli::Mode::Replay { path, speed } = &options.mode {
+        let replay = replay::Replay::load(path).map_err(|e| eyre!("{e:#}"))?;
+        playback = Some(replay::Playback::new(replay, *speed));
+    }
+    if let Some(playback) = &playback {
+        playback.apply(&mut options);                  // deal what the recording dealt
+    }
+
+    let mut content = content::Content::load(content::CONTENT_PATH)
+        .map_err(|e| eyre!("{e:#}"))?;
+    let words = options.rules.apply(&mut content).map_err(|e| eyre!("{e:#}"))?;
+    app::TableModel::use_words(words);
+
+    match &options.mode {                            // headless modes never touch the terminal
+        cli::Mode::Bench { boards, bots } => {
+            let difficulties = match options.rules.difficulty {
+                Some(d) => vec![d],
+                None => app::Difficulty::ALL.to_vec(),
+            };
+            let rows = bench::run(&content, &difficulties, *boards, bots, options.settings.game.seed.unwrap_or(0))
+                .map_err(|e| eyre!("{e:#}"))?;
+            bench::print_report(&rows);
+            return Ok(());
+        }
+        cli::Mode::Headless { bots } => {
+            let seed = options.settings.game.seed.unwrap_or_else(|| fastrand::u64(..));
+            bench::headless(&content, options.rules.difficulty.unwrap_or_default(), bots, seed)
+                .map_err(|e| eyre!("{e:#}"))?;
+            return Ok(());
+        }
+        cli::Mode::Help | cli::Mode::PrintConfig | cli::Mode::Play | cli::Mode::Replay { .. } => {}
+    }
+
     execute!(stdout(), EnableMouseCapture)?;         // init mousecapture
     let terminal: DefaultTerminal = ratatui::init(); // init ratatui terminal
-    let result = app::run(terminal);                 // run app loop
+    let result = app::run(terminal, content, options, playback); // run app loop
 
 
     ratatui::restore();                              // disable ratatui
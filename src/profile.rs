@@ -0,0 +1,115 @@
+// src/profile.rs
+// The hacker: a Science skill and perks, kept across sessions in
+// <data dir>/profile.toml. Like the original game each lock level asks for a
+// Science level, every SKILL_STEP over it plants an extra bracket pair and
+// every step under it plants one fewer. Science grows with every terminal
+// cracked and each SKILL_STEP earns a perk, picked on the profile screen.
+// Daily boards ignore the profile so everyone gets the same board.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::app::Difficulty;
+use crate::content::{Lock, MAX_ATTEMPTS};
+
+pub const PROFILE_FILE: &str = "profile.toml";
+pub const MAX_SCIENCE: u8 = 100;
+pub const SKILL_STEP: u8 = 25;   // science per bracket pair, and per perk slot
+pub const SKILL_PER_HACK: u8 = 5;
+pub const PERK_BRACKETS: usize = 2;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Perk {
+    ExtraAttempt,  // one more attempt on every board
+    MoreBrackets,  // PERK_BRACKETS more bracket pairs
+    AutoRemoveDud, // one dud is blanked out before the first guess
+}
+impl Perk {
+    pub const ALL: [Perk; 3] = [Perk::ExtraAttempt, Perk::MoreBrackets, Perk::AutoRemoveDud];
+
+    pub fn key(self) -> char {
+        match self {
+            Perk::ExtraAttempt  => '1',
+            Perk::MoreBrackets  => '2',
+            Perk::AutoRemoveDud => '3',
+        }
+    }
+    pub fn from_key(c: char) -> Option<Perk> {
+        Perk::ALL.into_iter().find(|p| p.key() == c)
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Perk::ExtraAttempt  => "Extra attempt",
+            Perk::MoreBrackets  => "More bracket pairs",
+            Perk::AutoRemoveDud => "Auto-remove a dud",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub science: u8,
+    pub perks: Vec<Perk>, // in the order they were picked
+}
+
+impl Profile {
+    pub fn load() -> Result<Self> {
+        Ok(crate::storage::load_toml(PROFILE_FILE)?.unwrap_or_default())
+    }
+    pub fn save(&self) -> Result<()> {
+        crate::storage::save_toml(PROFILE_FILE, self)
+    }
+
+    pub fn has(&self, perk: Perk) -> bool {
+        self.perks.contains(&perk)
+    }
+    // perks earned by science but not picked yet
+    pub fn free_slots(&self) -> usize {
+        ((self.science / SKILL_STEP) as usize).saturating_sub(self.perks.len())
+    }
+    // false if there's no free slot or it's already owned
+    pub fn pick(&mut self, perk: Perk) -> bool {
+        if self.free_slots() == 0 || self.has(perk) { return false; }
+        self.perks.push(perk);
+        true
+    }
+
+    // a terminal was cracked
+    pub fn hacked(&mut self) {
+        self.science = self.science.saturating_add(SKILL_PER_HACK).min(MAX_SCIENCE);
+    }
+
+    /* `lock` as this hacker sees it: bracket pairs up or down a pair for
+    *  every SKILL_STEP of science over or under the lock level's requirement,
+    *  then the perks. auto-removing a dud happens after the deal, see App
+    */
+    pub fn apply(&self, difficulty: Difficulty, lock: &Lock) -> Lock {
+        let mut lock = lock.clone();
+        let need = required_science(difficulty);
+        if self.science >= need {
+            lock.bracket_pairs += ((self.science - need) / SKILL_STEP) as usize;
+        } else {
+            let short = (need - self.science).div_ceil(SKILL_STEP) as usize;
+            lock.bracket_pairs = lock.bracket_pairs.saturating_sub(short);
+        }
+        if self.has(Perk::MoreBrackets) { lock.bracket_pairs += PERK_BRACKETS; }
+        if self.has(Perk::ExtraAttempt) { lock.attempts = (lock.attempts + 1).min(MAX_ATTEMPTS); }
+        lock
+    }
+}
+
+// Science the original game asks for at each lock level
+pub fn required_science(difficulty: Difficulty) -> u8 {
+    match difficulty {
+        Difficulty::Novice   => 0,
+        Difficulty::Advanced => 25,
+        Difficulty::Expert   => 50,
+        Difficulty::Master   => 75,
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+// src/profile/tests.rs
+// Science and perks against the lock levels, on dealt boards and through
+// the profile screen's keys.
+
+use super::{Perk, Profile, MAX_SCIENCE};
+use crate::app::{Difficulty, Screen};
+use crate::content::{Content, CONTENT_PATH};
+use crate::testing::{self, keys, press};
+use crate::title::DAILY_DIFFICULTY;
+
+const SEED: u64 = 50;
+
+fn hacker(science: u8, perks: &[Perk]) -> Profile {
+    Profile { science, perks: perks.to_vec() }
+}
+
+#[test]
+fn science_moves_bracket_pairs() {
+    let content = Content::load(CONTENT_PATH).unwrap();
+    let pairs = |science: u8, d: Difficulty| hacker(science, &[]).apply(d, content.lock(d)).bracket_pairs;
+    let base = |d: Difficulty| content.lock(d).bracket_pairs;
+
+    assert_eq!(pairs(0, Difficulty::Novice), base(Difficulty::Novice));
+    assert_eq!(pairs(100, Difficulty::Novice), base(Difficulty::Novice) + 4);
+    assert_eq!(pairs(60, Difficulty::Expert), base(Difficulty::Expert));
+    assert_eq!(pairs(40, Difficulty::Expert), base(Difficulty::Expert) - 1);
+    assert_eq!(pairs(0, Difficulty::Master), 0);
+}
+
+#[test]
+fn perks_change_the_lock() {
+    let content = Content::load(CONTENT_PATH).unwrap();
+    let lock = content.lock(Difficulty::Novice);
+    let perked = hacker(0, &[Perk::ExtraAttempt, Perk::MoreBrackets]).apply(Difficulty::Novice, lock);
+    assert_eq!(perked.attempts, lock.attempts + 1);
+    assert_eq!(perked.bracket_pairs, lock.bracket_pairs + super::PERK_BRACKETS);
+    assert_eq!(perked.word_len, lock.word_len);
+    assert_eq!(perked.word_count, lock.word_count);
+}
+
+#[test]
+fn perk_slots_come_with_science() {
+    let mut profile = hacker(20, &[]);
+    assert_eq!(profile.free_slots(), 0);
+    assert!(!profile.pick(Perk::ExtraAttempt));
+
+    profile.hacked();
+    assert_eq!(profile.free_slots(), 1);
+    assert!(profile.pick(Perk::ExtraAttempt));
+    assert!(!profile.pick(Perk::MoreBrackets));
+
+    for _ in 0..40 { profile.hacked(); }
+    assert_eq!(profile.science, MAX_SCIENCE);
+    assert_eq!(profile.free_slots(), 3);
+    assert!(!profile.pick(Perk::ExtraAttempt), "already owned");
+}
+
+#[test]
+fn perks_apply_to_dealt_boards_but_not_dailies() {
+    let mut app = testing::app(SEED);
+    app.profile = hacker(75, &[Perk::ExtraAttempt, Perk::AutoRemoveDud]);
+    let lock = app.content.lock(Difficulty::Novice).clone();
+
+    assert!(app.start_board(Difficulty::Novice, false));
+    assert_eq!(app.header.max_health, lock.attempts + 1);
+    assert_eq!(app.main.removed_duds.len(), 1);
+    assert_ne!(app.main.removed_duds[0], app.main.password);
+    assert_eq!(app.items, [">Perk: dud removed."]);
+
+    assert!(app.start_board(DAILY_DIFFICULTY, true));
+    assert_eq!(app.header.max_health, app.content.lock(DAILY_DIFFICULTY).attempts);
+    assert!(app.main.removed_duds.is_empty());
+}
+
+#[test]
+fn cracking_a_terminal_raises_science() {
+    let mut app = testing::board(SEED, Difficulty::Novice);
+    let password = app.main.password.clone();
+    assert!(testing::guess(&mut app, &password));
+    assert_eq!(app.profile.science, super::SKILL_PER_HACK);
+    assert!(app.profile_dirty);
+}
+
+#[test]
+fn profile_screen_picks_perks() {
+    let mut app = testing::app(SEED);
+    app.profile = hacker(30, &[]);
+    app.screen = Screen::Profile;
+
+    press(&mut app, &keys("2"));
+    assert_eq!(app.profile.perks, [Perk::MoreBrackets]);
+    assert!(app.profile_dirty);
+
+    // one slot at 30 science, it's taken
+    press(&mut app, &keys("1"));
+    assert_eq!(app.profile.perks, [Perk::MoreBrackets]);
+    assert_eq!(app.screen, Screen::Profile);
+    assert!(app.profile_note.as_deref().is_some_and(|note| note.starts_with("No perk to pick yet")));
+
+    press(&mut app, &keys("x"));
+    assert_eq!(app.screen, Screen::Title);
+    assert_eq!(app.profile_note, None);
+}
+
+#[test]
+fn owned_perk_says_so() {
+    let mut app = testing::app(SEED);
+    app.profile = hacker(60, &[Perk::MoreBrackets]);
+    app.screen = Screen::Profile;
+
+    press(&mut app, &keys("2"));
+    assert_eq!(app.profile.perks, [Perk::MoreBrackets]);
+    assert_eq!(app.profile_note, Some(format!("{} is already yours.", Perk::MoreBrackets.label())));
+
+    // a pick that goes through clears it
+    press(&mut app, &keys("1"));
+    assert_eq!(app.profile.perks.len(), 2);
+    assert_eq!(app.profile_note, None);
+}
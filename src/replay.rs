@@ -0,0 +1,129 @@
+// src/replay.rs
+// Input recording and playback. A live session records its seed and every
+// event (stamped with the tick it arrived on) to <data dir>/last-replay.toml,
+// `--replay <file>` feeds them back through the same handlers.
+
+use anyhow::{bail, Context, Result};
+use crossterm::event::Event;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::cli::{Options, Rules};
+use crate::profile::Profile;
+use crate::save::SaveGame;
+
+pub const REPLAY_FILE: &str = "last-replay.toml";
+// bump whenever Replay changes shape
+pub const REPLAY_VERSION: u32 = 6;
+
+pub const MIN_SPEED: f64 = 0.25;
+pub const MAX_SPEED: f64 = 16.0;
+
+#[derive(Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,                // fastrand seed the session started from
+    pub day: u64,                 // daily_seed() at the time, so Daily replays too
+    pub rules: Rules,             // command line options that shaped the boards
+    pub animations: bool,         // display.animations, off skips the title boot
+    pub profile: Profile,         // the hacker at the start, perks change boards too
+    pub resume: Option<SaveGame>, // board the session resumed, if any
+    #[serde(rename = "event", default)]
+    pub events: Vec<Recorded>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Recorded {
+    pub tick: u64,
+    pub event: Event,
+}
+
+#[derive(Deserialize)]
+struct ReplayVersion {
+    version: u32,
+}
+
+impl Replay {
+    pub fn new(seed: u64, day: u64, rules: Rules, animations: bool, profile: Profile) -> Self {
+        Self { version: REPLAY_VERSION, seed, day, rules, animations, profile, resume: None, events: Vec::new() }
+    }
+
+    pub fn record(&mut self, tick: u64, event: &Event) {
+        self.events.push(Recorded { tick, event: event.clone() });
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let ReplayVersion { version } = toml::from_str(&contents)
+            .with_context(|| format!("{} has no replay version", path.display()))?;
+        if version != REPLAY_VERSION {
+            bail!("{} is replay version {version}, this build reads version {REPLAY_VERSION}", path.display());
+        }
+        toml::from_str(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        crate::storage::save_toml(REPLAY_FILE, self)
+    }
+}
+
+
+// Walks a Replay in step with the app's tick counter
+pub struct Playback {
+    replay: Replay,
+    next: usize,
+    pub speed: f64, // ticks per TICK_RATE
+    pub paused: bool,
+}
+impl Playback {
+    pub fn new(replay: Replay, speed: f64) -> Self {
+        Self { replay, next: 0, speed: speed.clamp(MIN_SPEED, MAX_SPEED), paused: false }
+    }
+
+    pub fn seed(&self) -> u64 { self.replay.seed }
+    pub fn day(&self) -> u64 { self.replay.day }
+    pub fn profile(&self) -> Profile { self.replay.profile.clone() }
+    pub fn take_resume(&mut self) -> Option<SaveGame> { self.replay.resume.take() }
+
+    // playback deals and boots the title as the recording did
+    pub fn apply(&self, options: &mut Options) {
+        options.rules = self.replay.rules.clone();
+        options.settings.display.animations = self.replay.animations;
+    }
+
+    // next recorded event if it arrived on `tick`
+    pub fn due(&mut self, tick: u64) -> Option<Event> {
+        let recorded = self.replay.events.get(self.next)?;
+        if recorded.tick > tick { return None; }
+        self.next += 1;
+        Some(recorded.event.clone())
+    }
+
+    pub fn finished(&self) -> bool {
+        self.next >= self.replay.events.len()
+    }
+
+    pub fn faster(&mut self) {
+        self.speed = (self.speed * 2.0).min(MAX_SPEED);
+    }
+    pub fn slower(&mut self) {
+        self.speed = (self.speed / 2.0).max(MIN_SPEED);
+    }
+
+    // shown in the banner line while a replay runs
+    pub fn label(&self) -> String {
+        let state = if self.finished() {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
+        };
+        format!(" REPLAY {state} {}x   +/- speed   space pause   q quit ", self.speed)
+    }
+}
+
+#[cfg(test)]
+mod tests;
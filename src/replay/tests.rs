@@ -0,0 +1,124 @@
+// src/replay/tests.rs
+// A scripted session recorded the way run_live records it, written out and
+// read back as toml, then played through replay_app/replay_tick has to end
+// on the same board, guesses and history.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::Rect;
+
+use super::{Playback, Replay, REPLAY_VERSION};
+use crate::app::{handle_event, replay_app, replay_tick, App, Screen, TableModel};
+use crate::content::{Content, CONTENT_PATH};
+use crate::testing::{self, keys, walk_to};
+
+const SEED: u64 = 35;
+
+fn content() -> Content {
+    Content::load(CONTENT_PATH).expect("assets/content.toml should load")
+}
+
+// a live session and what it has recorded so far
+struct Live {
+    app: App,
+    replay: Replay,
+}
+impl Live {
+    fn new(animations: bool) -> Self {
+        let mut options = testing::options();
+        options.settings.display.animations = animations;
+        fastrand::seed(SEED);
+        let mut app = App::new(content(), &options).expect("the default board should deal");
+        app.day = 0;
+        let replay = Replay::new(SEED, app.day, options.rules.clone(), app.animations, app.profile.clone());
+        Self { app, replay }
+    }
+
+    // `wait` ticks go by, then each key is recorded and handled as run_live does
+    fn play(&mut self, wait: u64, codes: &[KeyCode]) {
+        for _ in 0..wait { self.app.on_tick(); }
+        for &code in codes {
+            let event = Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+            self.replay.record(self.app.tick, &event);
+            assert!(!handle_event(event, &mut self.app, Rect::default()), "the script doesn't quit");
+        }
+    }
+
+    // a cell of `word` without a bracket pair, so Enter guesses it
+    fn clean_cell(&self, word: &str) -> Option<(usize, usize)> {
+        testing::word_cells(&self.app.main, word)
+            .into_iter()
+            .find(|&(row, col)| TableModel::bracket_pairs(&self.app.table_contents[row][col]).is_empty())
+    }
+
+    fn guess(&mut self, wait: u64, (row, col): (usize, usize)) {
+        let mut codes = walk_to(&self.app, row, col);
+        codes.push(KeyCode::Enter);
+        self.play(wait, &codes);
+    }
+}
+
+// the title, a board picked from the menu, then duds until the lockout
+fn session(animations: bool) -> Live {
+    let mut live = Live::new(animations);
+    // while the boot text types the j only skips it, after that it moves the menu
+    live.play(5, &keys("j\n"));
+    assert_eq!(live.app.screen, Screen::Board);
+    let attempts = usize::from(live.app.header.health_i);
+    let cells: Vec<_> = testing::duds(&live.app.main).iter().filter_map(|dud| live.clean_cell(dud)).collect();
+    for (wait, &cell) in (3..).zip(cells.iter().take(attempts)) {
+        live.guess(wait, cell);
+    }
+    assert!(live.app.game_over);
+    live
+}
+
+// `replay` written to a file and loaded back
+fn round_trip(replay: &Replay, name: &str) -> anyhow::Result<Replay> {
+    let path = std::env::temp_dir().join(format!("fallout-game-{name}-{}.toml", std::process::id()));
+    std::fs::write(&path, toml::to_string(replay).expect("a replay should serialize")).unwrap();
+    let loaded = Replay::load(&path);
+    std::fs::remove_file(&path).ok();
+    loaded
+}
+
+// plays `replay` through to its last event for a viewer with `animations`
+fn play_back(replay: Replay, animations: bool) -> App {
+    let mut options = testing::options();
+    options.settings.display.animations = animations;
+    let mut playback = Playback::new(replay, 1.0);
+    playback.apply(&mut options);
+    let mut app = replay_app(content(), &options, &mut playback).expect("the recorded board should deal");
+    while !playback.finished() {
+        assert!(!replay_tick(&mut app, &mut playback, Rect::default()), "the recording doesn't quit");
+    }
+    app
+}
+
+#[test]
+fn playback_ends_where_the_session_did() {
+    for animations in [true, false] {
+        let live = session(animations);
+        let replay = round_trip(&live.replay, "replay").expect("a fresh replay should load");
+        assert_eq!(replay.events.len(), live.replay.events.len());
+
+        // the viewer's own setting doesn't count, the recording's does
+        let played = play_back(replay, !animations);
+        let live = &live.app;
+        assert_eq!(played.main.word_list, live.main.word_list, "animations = {animations}");
+        assert_eq!(played.main.password, live.main.password);
+        assert_eq!(played.main.guesses, live.main.guesses);
+        assert_eq!(played.items, live.items);
+        assert_eq!(played.header.health_i, live.header.health_i);
+        assert_eq!(played.header.status, live.header.status);
+        assert_eq!((played.screen, played.game_over), (live.screen, live.game_over));
+        assert_eq!(played.tick, live.tick);
+    }
+}
+
+#[test]
+fn other_versions_are_refused() {
+    let mut replay = session(false).replay;
+    replay.version = REPLAY_VERSION - 1;
+    let err = round_trip(&replay, "old-replay").err().expect("an old replay should be refused");
+    assert!(format!("{err:#}").contains(&format!("this build reads version {REPLAY_VERSION}")), "{err:#}");
+}
@@ -0,0 +1,94 @@
+// src/save.rs
+// In-progress board written to <data dir>/save.toml on quit, resumed on launch
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{Header, TableModel};
+use crate::campaign::Run;
+use crate::content::MAX_ATTEMPTS;
+
+pub const SAVE_FILE: &str = "save.toml";
+// bump whenever SaveGame or anything inside it changes shape
+pub const SAVE_VERSION: u32 = 4;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SaveGame {
+    pub version: u32,
+    pub brand: usize,
+    pub daily: bool,
+    pub elapsed_ms: u64,      // time already spent on the board
+    pub history: Vec<String>, // Side panel items
+    pub header: Header,
+    pub board: TableModel,    // words, junk (spent brackets included), duds, guesses
+    #[serde(default)]
+    pub run: Option<Run>,     // the terminal run the board is part of
+}
+
+// read just the version first so an old save is rejected with a clear reason
+// instead of whatever field happened to fail to parse
+#[derive(Deserialize)]
+struct SaveVersion {
+    version: u32,
+}
+
+impl SaveGame {
+    pub fn load() -> Result<Option<Self>> {
+        let path = crate::storage::data_file(SAVE_FILE);
+        if !path.exists() { return Ok(None); }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::parse(&contents)
+            .with_context(|| format!("can't resume from {}", path.display()))
+            .map(Some)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let SaveVersion { version } = toml::from_str(contents)
+            .context("save has no version")?;
+        if version != SAVE_VERSION {
+            bail!("save is version {version}, this build reads version {SAVE_VERSION}");
+        }
+        let mut save: SaveGame = toml::from_str(contents)?;
+
+        // a hand-edited save has to still be a live board
+        let header = &save.header;
+        if header.max_health > MAX_ATTEMPTS {
+            bail!("saved max_health = {} must be {MAX_ATTEMPTS} or less", header.max_health);
+        }
+        if header.health_i == 0 || header.health_i > header.max_health {
+            bail!("saved health_i = {} must be between 1 and max_health = {}", header.health_i, header.max_health);
+        }
+        let board = &save.board;
+        if board.junk_word_list.len() != board.word_list.len() * 2 + 1 {
+            bail!("saved board is malformed");
+        }
+        if !board.word_list.contains(&board.password) {
+            bail!("saved password isn't on the board");
+        }
+        save.board.play_space = TableModel::build_play_space(&save.board.junk_word_list)
+            .context("saved board is malformed")?;
+        Ok(save)
+    }
+
+    pub fn exists() -> bool {
+        crate::storage::data_file(SAVE_FILE).exists()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        crate::storage::save_toml(SAVE_FILE, self)
+    }
+
+    // a finished or resumed board leaves nothing to come back to
+    pub fn clear() -> Result<()> {
+        let path = crate::storage::data_file(SAVE_FILE);
+        if path.exists() {
+            std::fs::remove_file(&path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+// src/save/tests.rs
+// A save written by a seeded board reads back as the same board, and saves
+// from another version or edited into something unplayable are refused.
+
+use super::{SaveGame, SAVE_VERSION};
+use crate::app::{App, Difficulty};
+use crate::testing;
+
+// a board one guess in
+fn board() -> App {
+    let mut app = testing::board(34, Difficulty::Novice);
+    let dud = testing::duds(&app.main)[0].clone();
+    testing::guess(&mut app, &dud);
+    app
+}
+
+fn saved() -> String {
+    toml::to_string(&board().snapshot()).expect("a save should serialize")
+}
+
+// the saved toml with one line swapped out
+fn edited(from: &str, to: &str) -> String {
+    let contents = saved();
+    assert!(contents.contains(from), "save has no {from:?}");
+    contents.replacen(from, to, 1)
+}
+
+#[test]
+fn round_trips() {
+    let app = board();
+    let save = SaveGame::parse(&saved()).expect("a fresh save should parse");
+
+    assert_eq!(save.version, SAVE_VERSION);
+    assert_eq!(save.board.word_list, app.main.word_list);
+    assert_eq!(save.board.password, app.main.password);
+    assert_eq!(save.board.guesses, app.main.guesses);
+    // play_space isn't saved, it's rebuilt from the junk
+    assert_eq!(save.board.play_space, app.main.play_space);
+    assert_eq!(save.header.health_i, app.header.health_i);
+    assert_eq!(save.history, app.items);
+}
+
+#[test]
+fn other_versions_are_refused() {
+    let old = edited(&format!("version = {SAVE_VERSION}"), &format!("version = {}", SAVE_VERSION - 1));
+    let err = SaveGame::parse(&old).err().expect("an old save should be refused");
+    assert!(format!("{err:#}").contains("this build reads version"), "{err:#}");
+
+    assert!(SaveGame::parse("brand = 0").is_err());
+}
+
+#[test]
+fn malformed_saves_are_refused() {
+    let contents = saved();
+    let health = contents.lines().find(|l| l.starts_with("health_i")).unwrap().to_string();
+    let max = contents.lines().find(|l| l.starts_with("max_health")).unwrap().to_string();
+    let password = contents.lines().find(|l| l.starts_with("password")).unwrap().to_string();
+
+    for (from, to) in [
+        (health.as_str(), "health_i = 0"),
+        (health.as_str(), "health_i = 9"),
+        (max.as_str(), "max_health = 6"),
+        (password.as_str(), "password = \"zzzzz\""),
+    ] {
+        let broken = edited(from, to);
+        assert!(SaveGame::parse(&broken).is_err(), "{to} should be refused");
+    }
+    assert!(SaveGame::parse(&format!("version = {SAVE_VERSION}\nnot a save")).is_err());
+}
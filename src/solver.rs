@@ -0,0 +1,162 @@
+// src/solver.rs
+// Password solver. Keeps the words that agree with every likeness result so
+// far and ranks guesses by how many candidates each one could leave behind.
+// Pure functions over word lists, so it runs headless (bench, tests) as well
+// as behind the in-game hint.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::app::TableModel;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Strategy {
+    #[default]
+    Minimax,  // smallest worst-case bucket
+    Expected, // smallest expected bucket
+}
+impl Strategy {
+    pub const ALL: [Strategy; 2] = [Strategy::Minimax, Strategy::Expected];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Strategy::Minimax  => "minimax",
+            Strategy::Expected => "expected",
+        }
+    }
+}
+
+// words that would have produced every (guess, likeness) pair seen so far
+pub fn consistent(words: &[String], feedback: &[(String, usize)]) -> Vec<String> {
+    words
+        .iter()
+        .filter(|w| feedback.iter().all(|(g, l)| TableModel::likeness(g, w) == *l))
+        .cloned()
+        .collect()
+}
+
+// likeness -> how many candidates would answer `guess` with it
+pub fn partition(guess: &str, candidates: &[String]) -> BTreeMap<usize, usize> {
+    let mut buckets = BTreeMap::new();
+    for c in candidates {
+        *buckets.entry(TableModel::likeness(guess, c)).or_insert(0) += 1;
+    }
+    buckets
+}
+
+/* candidates left after guessing `guess`, lower is better. hitting the
+*  password leaves nothing, so the exact-match bucket is never counted
+*/
+pub fn score(guess: &str, candidates: &[String], strategy: Strategy) -> f64 {
+    let exact = guess.chars().count();
+    let left = partition(guess, candidates)
+        .into_iter()
+        .filter(|(likeness, _)| *likeness != exact)
+        .map(|(_, n)| n);
+
+    match strategy {
+        Strategy::Minimax => left.max().unwrap_or(0) as f64,
+        Strategy::Expected => {
+            if candidates.is_empty() { return 0.0; }
+            left.map(|n| (n * n) as f64).sum::<f64>() / candidates.len() as f64
+        }
+    }
+}
+
+/* every word in `pool` with its score, best first. ties go to words that
+*  could still be the password, then alphabetical so results are stable
+*/
+pub fn rank(pool: &[String], candidates: &[String], strategy: Strategy) -> Vec<(String, f64)> {
+    let mut ranked: Vec<(String, f64)> = pool
+        .iter()
+        .map(|g| (g.clone(), score(g, candidates, strategy)))
+        .collect();
+    ranked.sort_by(|(a, sa), (b, sb)| {
+        sa.total_cmp(sb)
+            .then_with(|| candidates.contains(b).cmp(&candidates.contains(a)))
+            .then_with(|| a.cmp(b))
+    });
+    ranked
+}
+
+// best next guess out of `pool` given the feedback so far
+pub fn best_guess(
+    pool: &[String],
+    words: &[String],
+    feedback: &[(String, usize)],
+    strategy: Strategy,
+) -> Option<String> {
+    let candidates = consistent(words, feedback);
+    match candidates.len() {
+        0 => None,
+        1 => Some(candidates[0].clone()),
+        _ => rank(pool, &candidates, strategy).into_iter().next().map(|(g, _)| g),
+    }
+}
+
+/* true if some guessing strategy over `pool` finds the password within
+*  `attempts` guesses whichever of `candidates` it turns out to be. deal_words
+*  runs this on every redeal, so likenesses are worked out once up front and
+*  every candidate set the search reaches is remembered with its answer
+*/
+pub fn solvable(pool: &[String], candidates: &[String], attempts: u8) -> bool {
+    let word_len = candidates.iter().chain(pool).map(|w| w.chars().count()).max().unwrap_or(0);
+    let mut search = Search {
+        likeness: pool
+            .iter()
+            .map(|g| candidates.iter().map(|c| TableModel::likeness(g, c)).collect())
+            .collect(),
+        // which candidate each pool word is, guessing it can win outright
+        own: pool.iter().map(|g| candidates.iter().position(|c| c == g)).collect(),
+        word_len,
+        memo: HashMap::new(),
+    };
+    let all: Vec<usize> = (0..candidates.len()).collect();
+    search.solvable(&all, attempts)
+}
+
+// solvable's state: pool x candidates likeness table plus answers so far
+struct Search {
+    likeness: Vec<Vec<usize>>,
+    own: Vec<Option<usize>>,
+    word_len: usize,
+    memo: HashMap<(Vec<usize>, u8), bool>,
+}
+impl Search {
+    fn solvable(&mut self, set: &[usize], attempts: u8) -> bool {
+        let n = set.len();
+        // guessing the candidates one by one rules out one each time
+        if n <= usize::from(attempts) { return true; }
+        if attempts <= 1 || n > self.capacity(attempts) { return false; }
+
+        let key = (set.to_vec(), attempts);
+        if let Some(&known) = self.memo.get(&key) { return known; }
+        let found = (0..self.likeness.len()).any(|guess| {
+            let buckets = self.split(guess, set);
+            // a guess that tells nothing apart can't be part of a shortest solve
+            if buckets.len() == 1 && buckets[0].len() == n { return false; }
+            buckets.iter().all(|bucket| self.solvable(bucket, attempts - 1))
+        });
+        self.memo.insert(key, found);
+        found
+    }
+
+    /* most candidates `attempts` guesses can ever tell apart: a guess wins
+    *  on one and splits the rest by likeness 0..word_len, each bucket with
+    *  one attempt fewer
+    */
+    fn capacity(&self, attempts: u8) -> usize {
+        (1..attempts).fold(1usize, |cap, _| cap.saturating_mul(self.word_len).saturating_add(1))
+    }
+
+    // `set` grouped by likeness to pool word `guess`, the guess itself left out
+    fn split(&self, guess: usize, set: &[usize]) -> Vec<Vec<usize>> {
+        let mut buckets: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for &c in set.iter().filter(|&&c| self.own[guess] != Some(c)) {
+            buckets.entry(self.likeness[guess][c]).or_default().push(c);
+        }
+        buckets.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,159 @@
+// src/solver/tests.rs
+// The solver on hand-made boards small enough to check by hand, mostly six
+// candidates of four letters and two extra words that can only be guessed.
+
+use proptest::prelude::*;
+
+use super::{best_guess, consistent, partition, rank, score, solvable, Strategy};
+use crate::app::TableModel;
+
+fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|w| w.to_string()).collect()
+}
+
+/* solvable as first written, every guess tried on every set, nothing
+*  remembered. too slow for real boards, the yardstick for small ones
+*/
+fn plain_solvable(pool: &[String], candidates: &[String], attempts: u8) -> bool {
+    match candidates.len() {
+        0 => return true,
+        1 => return attempts >= 1,
+        _ if attempts == 0 => return false,
+        _ => {}
+    }
+    pool.iter().any(|guess| {
+        let mut buckets: std::collections::BTreeMap<usize, Vec<String>> = Default::default();
+        for c in candidates.iter().filter(|c| *c != guess) {
+            buckets.entry(TableModel::likeness(guess, c)).or_default().push(c.clone());
+        }
+        if buckets.len() == 1 && !candidates.contains(guess) { return false; }
+        buckets.values().all(|bucket| plain_solvable(pool, bucket, attempts - 1))
+    })
+}
+
+fn candidates() -> Vec<String> {
+    words(&["cbcd", "dccb", "dadd", "dbda", "bcdc", "adad"])
+}
+
+// the candidates plus two words that can't be the password
+fn pool() -> Vec<String> {
+    let mut pool = candidates();
+    pool.extend(words(&["ccad", "bbcc"]));
+    pool
+}
+
+#[test]
+fn consistent_keeps_words_that_agree_with_every_likeness() {
+    let all = candidates();
+    assert_eq!(consistent(&all, &[]), all);
+
+    // likeness to bbcc: cbcd 2, dccb 1, dadd 0, dbda 1, bcdc 2, adad 0
+    let one = vec![("bbcc".to_string(), 1)];
+    assert_eq!(consistent(&all, &one), words(&["dccb", "dbda"]));
+
+    // a guess that wasn't the password rules itself out
+    let two = vec![("bbcc".to_string(), 1), ("dbda".to_string(), 1)];
+    assert_eq!(consistent(&all, &two), words(&["dccb"]));
+
+    let impossible = vec![("bbcc".to_string(), 3)];
+    assert!(consistent(&all, &impossible).is_empty());
+}
+
+#[test]
+fn partition_counts_candidates_per_likeness() {
+    let buckets = partition("bbcc", &candidates());
+    assert_eq!(buckets.into_iter().collect::<Vec<_>>(), vec![(0, 2), (1, 2), (2, 2)]);
+
+    // a candidate lands in its own exact-match bucket
+    let buckets = partition("dbda", &candidates());
+    assert_eq!(buckets.into_iter().collect::<Vec<_>>(), vec![(0, 1), (1, 3), (2, 1), (4, 1)]);
+}
+
+#[test]
+fn worst_case_and_expected_disagree() {
+    let (pool, candidates) = (pool(), candidates());
+
+    // bbcc never leaves more than 2, dbda can leave 3 but usually leaves 1
+    assert_eq!(score("bbcc", &candidates, Strategy::Minimax), 2.0);
+    assert_eq!(score("dbda", &candidates, Strategy::Minimax), 3.0);
+    assert_eq!(score("bbcc", &candidates, Strategy::Expected), 2.0);
+    assert_eq!(score("dbda", &candidates, Strategy::Expected), 11.0 / 6.0);
+
+    let minimax = rank(&pool, &candidates, Strategy::Minimax);
+    let expected = rank(&pool, &candidates, Strategy::Expected);
+    assert_eq!(minimax[0], ("bbcc".to_string(), 2.0));
+    assert_eq!(expected[0], ("dbda".to_string(), 11.0 / 6.0));
+    assert_eq!(minimax.len(), pool.len());
+    assert!(minimax.windows(2).all(|w| w[0].1 <= w[1].1), "ranked best first");
+}
+
+#[test]
+fn rank_ties_prefer_possible_passwords() {
+    // cbcd and dccb score the same, both could win so it's alphabetical
+    let ranked = rank(&words(&["dccb", "cbcd"]), &candidates(), Strategy::Minimax);
+    assert_eq!(ranked[0].0, "cbcd");
+    assert_eq!(ranked[1].0, "dccb");
+
+    // with two left, every word that tells them apart scores 1, but only
+    // guessing one of them can win outright
+    let ranked = rank(&pool(), &words(&["cbcd", "dccb"]), Strategy::Minimax);
+    assert!(words(&["cbcd", "dccb"]).contains(&ranked[0].0));
+}
+
+#[test]
+fn best_guess_follows_the_strategy() {
+    let (pool, all) = (pool(), candidates());
+    assert_eq!(best_guess(&pool, &all, &[], Strategy::Minimax).as_deref(), Some("bbcc"));
+    assert_eq!(best_guess(&pool, &all, &[], Strategy::Expected).as_deref(), Some("dbda"));
+
+    // one candidate left is the answer, whatever the pool
+    let feedback = vec![("bbcc".to_string(), 1), ("dbda".to_string(), 1)];
+    assert_eq!(best_guess(&pool, &all, &feedback, Strategy::Minimax).as_deref(), Some("dccb"));
+}
+
+#[test]
+fn best_guess_on_an_empty_pool() {
+    let all = candidates();
+    assert_eq!(best_guess(&[], &all, &[], Strategy::Minimax), None);
+    assert_eq!(best_guess(&[], &[], &[], Strategy::Expected), None);
+    // nothing left agrees with the feedback
+    let impossible = vec![("bbcc".to_string(), 3)];
+    assert_eq!(best_guess(&pool(), &all, &impossible, Strategy::Minimax), None);
+}
+
+#[test]
+fn solvable_depends_on_attempts() {
+    // one guess of aaaa tells abbb (1) and bbbb (0) apart
+    let split = words(&["aaaa", "abbb", "bbbb"]);
+    assert!(!solvable(&split, &split, 1));
+    assert!(solvable(&split, &split, 2));
+
+    // every pair is likeness 2, each wrong guess only rules itself out
+    let alike = words(&["aaab", "aaba", "abaa", "baaa"]);
+    assert!(!solvable(&alike, &alike, 3));
+    assert!(solvable(&alike, &alike, 4));
+
+    let one = words(&["abcd"]);
+    assert!(solvable(&one, &one, 1));
+    assert!(!solvable(&one, &one, 0));
+    assert!(solvable(&one, &[], 0), "nothing left to find");
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn solvable_agrees_with_plain_search(
+        set in prop::collection::btree_set("[abc]{3}", 2..9),
+        attempts in 1u8..5,
+    ) {
+        let words: Vec<String> = set.into_iter().collect();
+        prop_assert_eq!(solvable(&words, &words, attempts), plain_solvable(&words, &words, attempts));
+        // and with a guess-only word in the pool
+        let extra = "cba".to_string();
+        if !words.contains(&extra) {
+            let pool: Vec<String> = words.iter().cloned().chain([extra]).collect();
+            prop_assert_eq!(solvable(&pool, &words, attempts), plain_solvable(&pool, &words, attempts));
+        }
+    }
+}
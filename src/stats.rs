@@ -0,0 +1,134 @@
+// src/stats.rs
+// Player statistics kept across sessions in <data dir>/stats.toml
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::app::Difficulty;
+use crate::campaign::Run;
+
+pub const STATS_FILE: &str = "stats.toml";
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub attempts_used: u32,          // guesses made, summed over every game
+    pub brackets_used: u32,
+    pub fastest_solve_ms: Option<u64>,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub per_difficulty: BTreeMap<String, Record>, // keyed by lowercase difficulty name
+
+    // timed mode (--timed), see clock::score
+    pub timed_played: u32,
+    pub timed_won: u32,
+    pub timed_score: u32, // summed over every timed win
+    pub best_timed_score: u32,
+
+    // terminal runs, see campaign. their terminals aren't counted as games
+    pub runs_played: u32,
+    pub best_run_score: u32,
+    pub best_run_terminals: u32, // most terminals cracked in one run
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Record {
+    pub played: u32,
+    pub won: u32,
+}
+
+// What a finished game hands to Stats::record
+pub struct GameResult {
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub attempts_used: u32,
+    pub brackets_used: u32,
+    pub elapsed_ms: u64,
+    pub time_left_ms: Option<u64>, // timed boards only
+}
+
+
+impl Stats {
+    pub fn load() -> Result<Self> {
+        Ok(crate::storage::load_toml(STATS_FILE)?.unwrap_or_default())
+    }
+    pub fn save(&self) -> Result<()> {
+        crate::storage::save_toml(STATS_FILE, self)
+    }
+
+    pub fn record(&mut self, game: &GameResult) {
+        self.games_played += 1;
+        self.attempts_used += game.attempts_used;
+        self.brackets_used += game.brackets_used;
+
+        let record = self.per_difficulty
+            .entry(game.difficulty.name().to_lowercase())
+            .or_default();
+        record.played += 1;
+
+        if game.won {
+            self.wins += 1;
+            record.won += 1;
+            self.current_streak += 1;
+            self.best_streak = self.best_streak.max(self.current_streak);
+            if self.fastest_solve_ms.is_none_or(|ms| game.elapsed_ms < ms) {
+                self.fastest_solve_ms = Some(game.elapsed_ms);
+            }
+        } else {
+            self.current_streak = 0;
+        }
+
+        if let Some(left_ms) = game.time_left_ms {
+            self.record_timed(game.difficulty, game.won, left_ms);
+        }
+    }
+
+    // a timed board, on its own or as a run's terminal
+    pub fn record_timed(&mut self, difficulty: Difficulty, won: bool, left_ms: u64) {
+        self.timed_played += 1;
+        if won {
+            let score = crate::clock::score(difficulty, left_ms);
+            self.timed_won += 1;
+            self.timed_score += score;
+            self.best_timed_score = self.best_timed_score.max(score);
+        }
+    }
+
+    pub fn record_run(&mut self, run: &Run) {
+        self.runs_played += 1;
+        self.best_run_score = self.best_run_score.max(run.score);
+        self.best_run_terminals = self.best_run_terminals.max(run.cracked.len() as u32);
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        percent(self.wins, self.games_played)
+    }
+    pub fn average_attempts(&self) -> f64 {
+        if self.games_played == 0 { return 0.0; }
+        self.attempts_used as f64 / self.games_played as f64
+    }
+    pub fn timed_win_rate(&self) -> f64 {
+        percent(self.timed_won, self.timed_played)
+    }
+    pub fn record_for(&self, difficulty: Difficulty) -> Record {
+        self.per_difficulty
+            .get(&difficulty.name().to_lowercase())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+impl Record {
+    pub fn win_rate(&self) -> f64 {
+        percent(self.won, self.played)
+    }
+}
+
+fn percent(n: u32, of: u32) -> f64 {
+    if of == 0 { return 0.0; }
+    n as f64 * 100.0 / of as f64
+}
@@ -0,0 +1,62 @@
+// src/storage.rs
+// Where things that outlive a session (stats, saves, profile) are kept
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::PathBuf;
+
+const APP_DIR: &str = "fallout-game";
+
+/* per-user data directory, following XDG on unix and APPDATA on windows.
+*  FALLOUT_GAME_DATA overrides it entirely (handy for tests and portable installs)
+*/
+pub fn data_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("FALLOUT_GAME_DATA") {
+        return PathBuf::from(dir);
+    }
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join(APP_DIR)
+}
+
+// per-user config directory, same idea as data_dir. FALLOUT_GAME_CONFIG overrides
+pub fn config_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("FALLOUT_GAME_CONFIG") {
+        return PathBuf::from(dir);
+    }
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join(APP_DIR)
+}
+
+pub fn data_file(name: &str) -> PathBuf {
+    data_dir().join(name)
+}
+
+// None when the file doesn't exist yet, an error when it exists but is bad
+pub fn load_toml<T: DeserializeOwned>(name: &str) -> Result<Option<T>> {
+    let path = data_file(name);
+    if !path.exists() { return Ok(None); }
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let value = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    Ok(Some(value))
+}
+
+pub fn save_toml<T: Serialize>(name: &str, value: &T) -> Result<()> {
+    let path = data_file(name);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    let contents = toml::to_string_pretty(value)?;
+    std::fs::write(&path, contents)
+        .with_context(|| format!("failed to write {}", path.display()))
+}
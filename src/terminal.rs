@@ -0,0 +1,62 @@
+// src/terminal.rs
+// Unlocked terminal: the menu of entries a successful hack gives access to
+
+use serde::Deserialize;
+
+// One [[brand.entry]] in assets/content.toml
+#[derive(Clone, Debug, Deserialize)]
+pub struct Entry {
+    pub title: String,
+    #[serde(flatten)]
+    pub kind: EntryKind,
+}
+
+// Logs and memos are read, toggles ("Disable Turret") flip a switch
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum EntryKind {
+    Text { body: String },
+    Toggle { on: String, off: String },
+}
+
+
+pub struct TerminalScreen {
+    pub entries: Vec<Entry>,
+    pub selected: usize,
+    pub open: Option<usize>,  // text entry currently being read
+    pub switched: Vec<bool>,  // per entry, only meaningful for toggles
+    pub message: String,      // last toggle result
+}
+impl TerminalScreen {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        let switched = vec![false; entries.len()];
+        Self { entries, selected: 0, open: None, switched, message: String::new() }
+    }
+
+    pub fn up(&mut self) {
+        if self.open.is_some() || self.entries.is_empty() { return; }
+        let len = self.entries.len();
+        self.selected = (self.selected + len - 1) % len;
+    }
+    pub fn down(&mut self) {
+        if self.open.is_some() || self.entries.is_empty() { return; }
+        self.selected = (self.selected + 1) % self.entries.len();
+    }
+
+    // Enter: read a text entry or flip a toggle
+    pub fn activate(&mut self) {
+        let Some(entry) = self.entries.get(self.selected) else { return; };
+        match &entry.kind {
+            EntryKind::Text { .. } => { self.open = Some(self.selected); }
+            EntryKind::Toggle { on, off } => {
+                let state = &mut self.switched[self.selected];
+                *state = !*state;
+                self.message = if *state { on.clone() } else { off.clone() };
+            }
+        }
+    }
+    // Esc / Backspace: back out of an open entry
+    pub fn back(&mut self) {
+        self.open = None;
+    }
+}
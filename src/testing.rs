@@ -0,0 +1,90 @@
+// src/testing.rs
+// Fixtures shared by the unit tests: an App dealt from a fixed seed, and
+// helpers to put the cursor on a word and guess it like a player would, or
+// to script it as key presses through input::handle_key.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::{App, Difficulty, TableModel, CELL_LEN, TOTAL_CELLS};
+use crate::cli::{Mode, Options, Rules};
+use crate::config::Settings;
+use crate::content::{Content, CONTENT_PATH};
+use crate::keymap::Keymap;
+
+// no config files, no flags
+pub fn options() -> Options {
+    Options { settings: Settings::default(), rules: Rules::default(), keymap: Keymap::default(), mode: Mode::Play }
+}
+
+// App on the title screen, everything random drawn from `seed`
+pub fn app(seed: u64) -> App {
+    fastrand::seed(seed);
+    let content = Content::load(CONTENT_PATH).expect("assets/content.toml should load");
+    let mut app = App::new(content, &options()).expect("the default board should deal");
+    app.day = 0; // daily_seed() moves every day
+    app
+}
+
+// straight onto a board with animations off, so every frame is the final one
+pub fn board(seed: u64, difficulty: Difficulty) -> App {
+    let mut app = app(seed);
+    if app.animations { app.toggle_animations(); }
+    assert!(app.start_board(difficulty, false), "{} board should deal", difficulty.name());
+    app
+}
+
+// (row, col) of every cell that resolves to `word`, empty if it isn't on the board
+pub fn word_cells(board: &TableModel, word: &str) -> Vec<(usize, usize)> {
+    let Some(i) = board.word_list.iter().position(|w| w == word) else { return Vec::new(); };
+    let span = board.word_spans()[i].clone();
+    (span.start / CELL_LEN..span.end.div_ceil(CELL_LEN))
+        .filter(|&cell| board.word_at_cell(cell).as_deref() == Some(word))
+        .map(|cell| {
+            let half = TOTAL_CELLS / 2;
+            if cell < half { (cell, 1) } else { (cell - half, 3) }
+        })
+        .collect()
+}
+
+// cursor onto a cell that resolves to `word`, false if it isn't on the board
+pub fn select_word(app: &mut App, word: &str) -> bool {
+    let Some(&(row, col)) = word_cells(&app.main, word).first() else { return false; };
+    app.state.select(Some(row));
+    app.col_state = col;
+    true
+}
+
+pub fn guess(app: &mut App, word: &str) -> bool {
+    select_word(app, word) && app.submit_guess()
+}
+
+// every word on the board except the password, in board order
+pub fn duds(board: &TableModel) -> Vec<String> {
+    board.word_list.iter().filter(|w| **w != board.password).cloned().collect()
+}
+
+// plain keys from a string, '\n' is Enter and '\t' is Tab
+pub fn keys(script: &str) -> Vec<KeyCode> {
+    script.chars().map(|c| match c {
+        '\n' => KeyCode::Enter,
+        '\t' => KeyCode::Tab,
+        c => KeyCode::Char(c),
+    }).collect()
+}
+
+// feeds `codes` to handle_key one press at a time, true (and no more keys)
+// as soon as one of them quits
+pub fn press(app: &mut App, codes: &[KeyCode]) -> bool {
+    codes.iter().any(|&code| crate::input::handle_key(KeyEvent::new(code, KeyModifiers::NONE), app))
+}
+
+// j/l presses that walk the board cursor from where it is to (row, col),
+// both wrap so it never needs k or h
+pub fn walk_to(app: &App, row: usize, col: usize) -> Vec<KeyCode> {
+    let rows = app.table_contents.len();
+    let cols = app.ts.columns;
+    let at_row = app.state.selected().unwrap_or(0);
+    let down = (row + rows - at_row) % rows;
+    let right = (col + cols - app.col_state) % cols;
+    keys(&format!("{}{}", "j".repeat(down), "l".repeat(right)))
+}
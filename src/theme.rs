@@ -0,0 +1,92 @@
+// src/theme.rs
+// CRT phosphor palettes, every widget in ui.rs pulls its colours from here
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeKind {
+    #[default]
+    Green,
+    Amber,
+    White,
+}
+impl ThemeKind {
+    pub const ALL: [ThemeKind; 3] = [ThemeKind::Green, ThemeKind::Amber, ThemeKind::White];
+
+    // theme selector, cycles green -> amber -> white
+    pub fn next(&mut self) {
+        *self = match *self {
+            ThemeKind::Green => ThemeKind::Amber,
+            ThemeKind::Amber => ThemeKind::White,
+            ThemeKind::White => ThemeKind::Green,
+        };
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemeKind::Green => "green",
+            ThemeKind::Amber => "amber",
+            ThemeKind::White => "white",
+        }
+    }
+    pub fn from_name(name: &str) -> Option<ThemeKind> {
+        ThemeKind::ALL.into_iter().find(|t| t.name() == name)
+    }
+
+    pub fn theme(self) -> Theme {
+        match self {
+            ThemeKind::Green => Theme {
+                fg:     Color::Rgb(51, 255, 102),
+                dim:    Color::Rgb(20, 110, 45),
+                bright: Color::Rgb(190, 255, 200),
+                bg:     Color::Black,
+            },
+            ThemeKind::Amber => Theme {
+                fg:     Color::Rgb(255, 176, 0),
+                dim:    Color::Rgb(125, 80, 0),
+                bright: Color::Rgb(255, 225, 150),
+                bg:     Color::Black,
+            },
+            ThemeKind::White => Theme {
+                fg:     Color::Rgb(210, 210, 210),
+                dim:    Color::Rgb(110, 110, 110),
+                bright: Color::White,
+                bg:     Color::Black,
+            },
+        }
+    }
+}
+
+
+// One phosphor colour at three intensities on a black screen
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Theme {
+    pub fg: Color,     // normal text
+    pub dim: Color,    // junk, spent brackets, inactive borders
+    pub bright: Color, // words and anything that needs attention
+    pub bg: Color,
+}
+impl Theme {
+    pub fn base(&self) -> Style {
+        Style::default().fg(self.fg).bg(self.bg)
+    }
+    pub fn dim(&self) -> Style {
+        Style::default().fg(self.dim).bg(self.bg)
+    }
+    pub fn bright(&self) -> Style {
+        Style::default().fg(self.bright).bg(self.bg).add_modifier(Modifier::BOLD)
+    }
+    // the selected cell, drawn as an inverted block like the in-game cursor
+    pub fn highlight(&self) -> Style {
+        Style::default().fg(self.bg).bg(self.bright).add_modifier(Modifier::BOLD)
+    }
+    pub fn border(&self, focused: bool) -> Style {
+        if focused { self.bright() } else { self.dim() }
+    }
+    // popups (help, debug, game over) get a bright frame to stand out
+    pub fn popup_border(&self) -> Style {
+        self.bright()
+    }
+}
@@ -0,0 +1,95 @@
+// src/title.rs
+// Startup screen: terminal boot text followed by a menu to pick a lock
+
+use crate::anim::Typewriter;
+use crate::app::Difficulty;
+use crate::content::Content;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MenuItem {
+    Play(Difficulty),
+    Daily, // one shared board per calendar day
+    Run,   // terminal after terminal until a lockout, see campaign
+    Profile,
+    Stats,
+}
+impl MenuItem {
+    pub const ALL: [MenuItem; 8] = [
+        MenuItem::Play(Difficulty::Novice),
+        MenuItem::Play(Difficulty::Advanced),
+        MenuItem::Play(Difficulty::Expert),
+        MenuItem::Play(Difficulty::Master),
+        MenuItem::Daily,
+        MenuItem::Run,
+        MenuItem::Profile,
+        MenuItem::Stats,
+    ];
+
+    pub fn label(self) -> String {
+        match self {
+            MenuItem::Play(d) => format!("[{}]", d.name()),
+            MenuItem::Daily   => "[Daily Terminal]".to_string(),
+            MenuItem::Run     => "[Terminal Run]".to_string(),
+            MenuItem::Profile => "[PROFILE]".to_string(),
+            MenuItem::Stats   => "[STATS]".to_string(),
+        }
+    }
+}
+
+
+pub struct TitleScreen {
+    pub brand: usize,       // index into Content::brand
+    pub boot: Vec<String>,  // that brand's boot text
+    pub selected: usize,    // index into MenuItem::ALL
+    pub boot_fx: Typewriter,
+}
+impl TitleScreen {
+    // picks a random terminal brand for this run
+    pub fn new(content: &Content) -> Self {
+        Self::with_brand(content, fastrand::usize(..content.brand.len()))
+    }
+    pub fn with_brand(content: &Content, brand: usize) -> Self {
+        let brand = brand.min(content.brand.len() - 1);
+        Self {
+            brand,
+            boot: content.brand(brand).boot.clone(),
+            selected: 0,
+            boot_fx: Typewriter::new(2),
+        }
+    }
+
+    pub fn boot_chars(&self) -> usize {
+        self.boot.iter().map(|s| s.chars().count()).sum()
+    }
+    // the menu only takes input once the boot text has finished typing
+    pub fn booted(&self) -> bool {
+        self.boot_fx.shown() == self.boot_chars()
+    }
+    pub fn skip_boot(&mut self) {
+        let total = self.boot_chars();
+        self.boot_fx.finish(total);
+    }
+
+    pub fn menu_up(&mut self) {
+        let len = MenuItem::ALL.len();
+        self.selected = (self.selected + len - 1) % len;
+    }
+    pub fn menu_down(&mut self) {
+        self.selected = (self.selected + 1) % MenuItem::ALL.len();
+    }
+    pub fn selected_item(&self) -> MenuItem {
+        MenuItem::ALL[self.selected]
+    }
+}
+
+// Daily boards are always this lock
+pub const DAILY_DIFFICULTY: Difficulty = Difficulty::Advanced;
+
+// Daily boards are seeded by the day number so everyone gets the same lock
+pub fn daily_seed() -> u64 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    secs / 86_400
+}
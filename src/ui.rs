@@ -1,173 +1,374 @@
 use ratatui::prelude::*;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::widgets::{
-    Block, Borders, List, ListItem, ListState, Paragraph,
-    Table, Row, Cell, Clear, Wrap, TableState,
+    Block, Borders, List, ListItem, Paragraph,
+    Table, Row, Cell, Clear, Wrap,
 };
 use ratatui::Frame;
-use ratatui::style::{Color, Modifier, Style};
 
-use crate::app::{App, Focus, DebugLog};
+use crate::anim::{take_chars, cursor_visible};
+use crate::app::{App, Difficulty, Focus, DebugLog, Screen, TableModel, BOARD_CHARS, CELL_LEN};
+use crate::campaign::Run;
+use crate::clock::Stage;
+use crate::hint::{Hint, HINT_BUDGET, HINT_COST};
+use crate::layout;
+use crate::profile::{self, Perk, MAX_SCIENCE, SKILL_PER_HACK};
+use crate::terminal::EntryKind;
+use crate::title::MenuItem;
+use crate::theme::Theme;
 
 pub fn render(frame: &mut Frame, app: &mut App) {
     let root = frame.area();
-    let mut ui = root;
-
-    // 4:3-ish aspect ratio logic
-    if ui.height * 10 / 3 <= ui.width {
-        ui.width = ui.height * 10 / 3; // width shrinks to fit height
-    } else {
-        ui.height = ui.width * 3 / 10; // height shrinks to fit width
+    let theme = app.theme.theme();
+
+    // paint the whole screen so the phosphor background fills the terminal
+    frame.render_widget(Block::default().style(theme.base()), root);
+
+    match app.screen {
+        Screen::Title => {
+            draw_title(frame, root, app, &theme);
+            draw_banner(frame, root, app, &theme);
+            return;
+        }
+        Screen::Terminal => {
+            draw_terminal(frame, root, app, &theme);
+            if app.show_help { draw_help(frame, root, &theme); }
+            if app.show_debug { draw_debug(frame, root, &app.debug, &theme); }
+            draw_banner(frame, root, app, &theme);
+            return;
+        }
+        Screen::Stats => {
+            draw_stats(frame, root, app, &theme);
+            draw_banner(frame, root, app, &theme);
+            return;
+        }
+        Screen::Profile => {
+            draw_profile(frame, root, app, &theme);
+            draw_banner(frame, root, app, &theme);
+            return;
+        }
+        Screen::Board => {}
     }
 
-    // Describe Layout
-    // ------------------------------------------------------------------------
-    // Vertical: header + body
-    let vertical = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(6),  // header 4 inner 2 border
-            Constraint::Min(0),     // body
-        ])
-        .split(ui);
-
-    let header_area = vertical[0];
-    let body_area   = vertical[1];
-
-    // Horizontal: main + sidebar
-    let body_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(70),
-            Constraint::Percentage(30),
-        ])
-        .split(body_area);
-
-    let main_area = body_chunks[0];
-    let side_area = body_chunks[1];
-
-    let side_area_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage(90),
-            Constraint::Percentage(10),
-        ])
-        .split(side_area);
-
-    let history_area = side_area_chunks[0];
-    let input_area   = side_area_chunks[1];
-
-    // End layout description
-    //-------------------------------------------------------------------------
+    let (board_w, board_h) = board_size(app);
+    let layout = match layout::board_layout(root, board_w, board_h) {
+        Ok(layout) => layout,
+        Err(needed) => {
+            draw_too_small(frame, root, needed, &theme);
+            draw_banner(frame, root, app, &theme);
+            return;
+        }
+    };
 
     // expose sidebar rect to app loop for hit-testing
     // *side_area_out = side_area;
 
-    draw_header(frame, header_area, app);
-    draw_main(frame, main_area, app);
-    draw_side(frame, side_area, history_area, input_area, app);
+    draw_header(frame, layout.header, layout.slim_header, app);
+    draw_main(frame, layout.main, app);
+    draw_side(frame, layout.side, layout.history, layout.input, app);
 
     // Popups
     if app.game_over {
-        draw_game_over(frame, root);
+        match &app.run {
+            Some(run) => draw_run_summary(frame, root, run, app.stats.best_run_score, &theme),
+            None => draw_game_over(frame, root, &theme),
+        }
+    }
+
+    if app.show_hints {
+        draw_hints(frame, root, app, &theme);
     }
 
     if app.show_help {
         let area = frame.area();
-        draw_help(frame, area);
+        draw_help(frame, area, &theme);
     }
 
     if app.show_debug {
         let area = frame.area();
-        draw_debug(frame, area, &app.debug);
+        draw_debug(frame, area, &app.debug, &theme);
     }
 
+    draw_banner(frame, root, app, &theme);
+
 }
 // End render 'ui runtime'
 // ----------------------------------------------------------------------------
 
 
-fn draw_header(frame: &mut Frame, area: Rect, app: &mut App) {
-    // App is being passed in for health, title and whatever
-    let block = match app.focus {
-        Focus::Head => Block::default()
-            .title("Header [active]")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
-        _ => Block::default().title("Header").borders(Borders::ALL),
-    };
+// Boot text typed out line by line, then the lock menu underneath
+fn draw_title(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let mut budget = if app.animations { app.title.boot_fx.shown() } else { usize::MAX };
+    let mut lines: Vec<Line> = Vec::new();
 
-    let text = Text::from(vec![
-        Line::from(app.header.title.clone()),
-        Line::from(app.header.status.clone()),
-        Line::default(), // blank line
-        Line::from(app.header.new_health_bar()),
-    ]);
+    for (i, boot) in app.title.boot.iter().enumerate() {
+        if budget == 0 { break; }
+        let shown = take_chars(boot, budget);
+        budget -= shown.chars().count();
+        // first line is the terminal brand
+        let style = if i == 0 { theme.bright() } else { theme.base() };
+        lines.push(Line::styled(shown.to_string(), style));
+    }
+
+    if app.title.booted() || !app.animations {
+        lines.push(Line::default());
+        lines.push(Line::styled("SELECT TERMINAL LOCK:", theme.bright()));
+        for (i, item) in MenuItem::ALL.iter().enumerate() {
+            let style = if i == app.title.selected { theme.highlight() } else { theme.base() };
+            lines.push(Line::styled(item.label(), style));
+        }
+        lines.push(Line::default());
+        lines.push(Line::styled("j/k select   enter start   q quit", theme.dim()));
+    } else if cursor_visible(app.tick, app.animations) {
+        lines.push(Line::styled("█", theme.bright()));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.border(true));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-    let content = Paragraph::new(text).block(block);
 
+// Unlocked terminal, the entry menu or the entry being read
+fn draw_terminal(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let mut lines: Vec<Line> = vec![
+        Line::styled(app.header.title.clone(), theme.bright()),
+        Line::from(app.header.status.clone()),
+        Line::default(),
+    ];
+
+    let terminal = &app.terminal;
+    match terminal.open.and_then(|i| terminal.entries.get(i)) {
+        Some(entry) => {
+            lines.push(Line::styled(format!("[{}]", entry.title), theme.bright()));
+            lines.push(Line::default());
+            if let EntryKind::Text { body } = &entry.kind {
+                lines.extend(body.lines().map(|l| Line::from(l.to_string())));
+            }
+            lines.push(Line::default());
+            lines.push(Line::styled("esc back   q quit", theme.dim()));
+        }
+        None => {
+            for (i, entry) in terminal.entries.iter().enumerate() {
+                let label = match entry.kind {
+                    EntryKind::Toggle { .. } => {
+                        let state = if terminal.switched[i] { "ON" } else { "OFF" };
+                        format!("> [{}] {state}", entry.title)
+                    }
+                    EntryKind::Text { .. } => format!("> [{}]", entry.title),
+                };
+                let style = if i == terminal.selected { theme.highlight() } else { theme.base() };
+                lines.push(Line::styled(label, style));
+            }
+            lines.push(Line::default());
+            lines.push(Line::from(terminal.message.clone()));
+            lines.push(Line::styled("j/k select   enter open   q quit", theme.dim()));
+        }
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.border(true));
+    let content = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block);
     frame.render_widget(content, area);
 }
 
 
-fn draw_main(frame: &mut Frame, area: Rect, app: &mut App) {
-    let block = match app.focus {
-        Focus::Main => Block::default()
-            .title("Main [active]")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
-        _ => Block::default().title("Main").borders(Borders::ALL),
+// STATS screen, lifetime totals then a line per lock level
+fn draw_stats(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let stats = &app.stats;
+    let fastest = match stats.fastest_solve_ms {
+        Some(ms) => format!("{}.{:01}s", ms / 1000, (ms % 1000) / 100),
+        None => "--".to_string(),
     };
 
-    let active_cell_style = Style::default()
-        .fg(Color::Yellow)
-        .add_modifier(Modifier::BOLD);
+    let mut lines: Vec<Line> = vec![
+        Line::styled("STATS", theme.bright()),
+        Line::default(),
+        Line::from(format!("Games played    {}", stats.games_played)),
+        Line::from(format!("Win rate        {:.0}%", stats.win_rate())),
+        Line::from(format!("Avg attempts    {:.1}", stats.average_attempts())),
+        Line::from(format!("Brackets used   {}", stats.brackets_used)),
+        Line::from(format!("Fastest solve   {fastest}")),
+        Line::from(format!("Streak          {} (best {})", stats.current_streak, stats.best_streak)),
+        Line::default(),
+    ];
+    if stats.timed_played > 0 {
+        lines.push(Line::from(format!("Timed won       {:.0}% of {}", stats.timed_win_rate(), stats.timed_played)));
+        lines.push(Line::from(format!("Timed score     {} (best {})", stats.timed_score, stats.best_timed_score)));
+        lines.push(Line::default());
+    }
+    if stats.runs_played > 0 {
+        lines.push(Line::from(format!("Terminal runs   {}", stats.runs_played)));
+        lines.push(Line::from(format!("Best run        {} pts, {} terminals", stats.best_run_score, stats.best_run_terminals)));
+        lines.push(Line::default());
+    }
+    for d in Difficulty::ALL {
+        let record = stats.record_for(d);
+        lines.push(Line::from(format!(
+            "{:<10} {:>3} played  {:>3.0}% won", d.name(), record.played, record.win_rate(),
+        )));
+    }
+    lines.push(Line::default());
+    lines.push(Line::styled("any key back   q quit", theme.dim()));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.border(true));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+
+// Science, what it does to each lock level, and the perks
+fn draw_profile(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let hacker = &app.profile;
+    let mut lines: Vec<Line> = vec![
+        Line::styled("PROFILE", theme.bright()),
+        Line::default(),
+        Line::from(format!("Science         {}/{MAX_SCIENCE}  (+{SKILL_PER_HACK} per terminal cracked)", hacker.science)),
+        Line::from(format!("Perks to pick   {}", hacker.free_slots())),
+        Line::default(),
+    ];
+    for d in Difficulty::ALL {
+        let lock = app.content.lock(d);
+        let pairs = hacker.apply(d, lock).bracket_pairs as i64 - lock.bracket_pairs as i64;
+        lines.push(Line::from(format!(
+            "{:<10} needs {:>3}  {:+} bracket pairs", d.name(), profile::required_science(d), pairs,
+        )));
+    }
+    lines.push(Line::default());
+    for perk in Perk::ALL {
+        let (mark, style) = if hacker.has(perk) { ("owned", theme.bright()) } else { ("", theme.base()) };
+        lines.push(Line::styled(format!("{}  {:<20} {mark}", perk.key(), perk.label()), style));
+    }
+    lines.push(Line::default());
+    if let Some(note) = &app.profile_note {
+        lines.push(Line::styled(note.clone(), theme.bright()));
+    }
+    lines.push(Line::styled("1-3 pick a perk   any other key back   q quit", theme.dim()));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.border(true));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 
-    let default_cell_style = Style::default();
+// slim = short terminal, title and status share the first line
+fn draw_header(frame: &mut Frame, area: Rect, slim: bool, app: &mut App) {
+    // App is being passed in for health, title and whatever
+    let theme = app.theme.theme();
+    let block = panel_block("Header", app.focus == Focus::Head, &theme);
+
+    // boot sequence, title then status typed out one char per tick
+    let budget = if app.animations { app.header_fx.shown() } else { usize::MAX };
+    let title = take_chars(&app.header.title, budget);
+    let status = take_chars(&app.header.status, budget.saturating_sub(title.chars().count()));
+
+    // attempts, then the countdown on timed boards and where a run is at
+    let mut health = vec![Span::raw(app.header.new_health_bar())];
+    if let Some(clock) = &app.header.clock {
+        let style = match clock.stage() {
+            Stage::Plenty => theme.base(),
+            Stage::Low => theme.bright(),
+            Stage::Critical | Stage::Expired => theme.highlight(),
+        };
+        health.push(Span::raw("   "));
+        health.push(Span::styled(format!("TIME {}", clock.label()), style));
+    }
+    if let Some(run) = &app.run {
+        health.push(Span::raw("   "));
+        health.push(Span::raw(format!("TERMINAL {}  SCORE {}", run.terminal(), run.score)));
+    }
 
-    let header_cells = (0..app.ts.columns)
-        .map(|i| Cell::from(format!("Col {i}")));
+    let text = if slim {
+        Text::from(vec![
+            Line::from(vec![
+                Span::styled(title.to_string(), theme.bright()),
+                Span::raw("  "),
+                Span::raw(status.to_string()),
+            ]),
+            Line::from(health),
+        ])
+    } else {
+        Text::from(vec![
+            Line::styled(title.to_string(), theme.bright()),
+            Line::from(status.to_string()),
+            Line::default(), // blank line
+            Line::from(health),
+        ])
+    };
 
-    let header = Row::new(header_cells)
-        .style(Style::default()
-        .add_modifier(Modifier::BOLD));
+    let content = Paragraph::new(text).style(theme.base()).block(block);
+
+    frame.render_widget(content, area);
+}
+
+
+fn draw_main(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = app.theme.theme();
+    let block = panel_block("Main", app.focus == Focus::Main, &theme);
+
+    let active_cell_style = theme.highlight();
 
     // -----------------------------
     // Build rows from app.table_contents
     // -----------------------------
     let selected_row = app.state.selected().unwrap_or(usize::MAX);
 
+    // board offsets covered by words the hint has ruled out
+    let mut dimmed = vec![false; BOARD_CHARS];
+    if app.dim_eliminated() {
+        let eliminated = app.main.eliminated_words();
+        for (span, word) in app.main.word_spans().into_iter().zip(&app.main.word_list) {
+            if eliminated.contains(word) {
+                dimmed[span].iter_mut().for_each(|d| *d = true);
+            }
+        }
+    }
+
     let rows = app.table_contents.iter().enumerate().map(|(row_idx, row)| {
         let active_col = app.col_state.min(row.len().saturating_sub(1));
 
         let cells = row.iter().enumerate().map(|(col_idx, val)| {
-            let mut cell = Cell::from(val.as_str()).style(default_cell_style);
-
+            let cell = TableModel::cell_index(row_idx, col_idx);
+            let text = match cell {
+                None => format!("{}{val}", app.hex_prefix),
+                Some(_) => val.clone(),
+            };
             if app.focus == Focus::Main
                 && row_idx == selected_row
                 && col_idx == active_col
             {
-                cell = cell.style(active_cell_style);
+                return Cell::from(text).style(active_cell_style);
             }
 
-            cell
+            // hex columns stay dim, play columns pick out the words
+            match cell {
+                None => Cell::from(text).style(theme.dim()),
+                Some(cell) => {
+                    let start = cell * CELL_LEN;
+                    let cell_dimmed = dimmed.get(start..start + CELL_LEN).unwrap_or(&[]);
+                    Cell::from(play_cell_line(val, cell_dimmed, &theme))
+                }
+            }
         });
 
         Row::new(cells)
     });
   
-    // -----------------------------
-    // Column widths (simple default)
-    // -----------------------------
-
-    // Ratatui needs a width per column. Since you don't store widths yet,
-    // just make them evenly split.
-    let widths: Vec<Constraint> = (0..app.ts.columns)
-        .map(|_| Constraint::Percentage((100 / app.ts.columns.max(1)) as u16))
-        .collect();
+    // exact widths, addresses as wide as the longest one and play cells CELL_LEN
+    let widths: Vec<Constraint> = app.ts.widths().into_iter().map(Constraint::Length).collect();
 
     let table = Table::new(rows, widths)
-        .header(header)
+        .style(theme.base())
         .block(block)
         .column_spacing(app.ts.column_spacing);
 
@@ -176,21 +377,39 @@ fn draw_main(frame: &mut Frame, area: Rect, app: &mut App) {
 }
 
 
+// width and height the board table needs so no play row is cut, borders included
+fn board_size(app: &App) -> (u16, u16) {
+    (2 + app.ts.width(), 2 + app.ts.rows as u16)
+}
+
+// Below every arrangement's minimum, say so instead of drawing a cut board
+fn draw_too_small(frame: &mut Frame, area: Rect, needed: (u16, u16), theme: &Theme) {
+    let text = vec![
+        Line::styled("TERMINAL TOO SMALL", theme.bright()),
+        Line::from(format!("need {}x{}, have {}x{}", needed.0, needed.1, area.width, area.height)),
+    ];
+    let height = (text.len() as u16).min(area.height);
+    let row = Rect { y: area.y + area.height.saturating_sub(height) / 2, height, ..area };
+    frame.render_widget(
+        Paragraph::new(text).alignment(Alignment::Center).wrap(Wrap { trim: true }).style(theme.base()),
+        row,
+    );
+}
+
 fn draw_side(frame: &mut Frame, side_area: Rect, history_area: Rect, input_area: Rect, app: &mut App) {
     let inner = draw_side_border(frame, side_area, app);
-    draw_side_history(frame, intersect(history_area, inner), app);
+    if app.show_tracker {
+        draw_side_tracker(frame, intersect(history_area, inner), app);
+    } else {
+        draw_side_history(frame, intersect(history_area, inner), app);
+    }
     draw_side_input(frame, intersect(input_area, inner), app);
 }
 
 // Handles focus rendering logic + border
 fn draw_side_border(frame: &mut Frame, area: Rect, app: &mut App) -> Rect {
-    let block = match app.focus {
-        Focus::Side => Block::default()
-            .title("Side [active]")
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
-        _ => Block::default().title("Side").borders(Borders::ALL),
-    };
+    let theme = app.theme.theme();
+    let block = panel_block("Side", app.focus == Focus::Side, &theme);
 
     let inner = block.inner(area); // return the inner area of the side_area
     frame.render_widget(block, area);
@@ -199,33 +418,94 @@ fn draw_side_border(frame: &mut Frame, area: Rect, app: &mut App) -> Rect {
 // List that scrolls up as you add items
 fn draw_side_history(frame: &mut Frame, area: Rect, app: &mut App) {
     let list_height = area.height as usize;
-    let total = app.items.len();
 
+    // typed-out history, lines past the typewriter's position aren't shown yet
+    let mut budget = if app.animations { app.history_fx.shown() } else { usize::MAX };
+    let mut typed: Vec<&str> = Vec::with_capacity(app.items.len());
+    for item in &app.items {
+        if budget == 0 { break; }
+        let line = take_chars(item, budget);
+        budget -= line.chars().count();
+        typed.push(line);
+    }
+
+    let total = typed.len();
     let start = total.saturating_sub(list_height);
-    let tail = &app.items[start..];
+    let tail = &typed[start..];
 
     let mut visible: Vec<ListItem> = Vec::new();
 
     let padding = list_height.saturating_sub(tail.len());
     visible.extend((0..padding).map(|_| ListItem::new("")));
 
-    visible.extend(tail.iter().map(|s| ListItem::new(s.clone())));
+    visible.extend(tail.iter().map(|s| ListItem::new(s.to_string())));
 
-    let list = List::new(visible);
+    let list = List::new(visible).style(app.theme.theme().base());
     frame.render_widget(list, area);
 }
+// Candidate tracker: every word still on the board, ruled out ones dimmed and
+// guessed ones tagged with their likeness
+fn draw_side_tracker(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = app.theme.theme();
+    let remaining = app.main.remaining_words();
+    let eliminated = app.main.eliminated_words();
+    let left = remaining.len() - eliminated.len();
+
+    let mut items: Vec<ListItem> = vec![
+        ListItem::new(Line::styled(format!("CANDIDATES {left}/{}", remaining.len()), theme.bright())),
+    ];
+    items.extend(remaining.iter().map(|word| {
+        let guessed = app.main.guesses.iter().find(|(g, _)| g == word);
+        let label = match guessed {
+            Some((_, likeness)) => format!(" {word} ={likeness}"),
+            None => format!(" {word}"),
+        };
+        let style = if eliminated.contains(word) { theme.dim() } else { theme.base() };
+        ListItem::new(Line::styled(label, style))
+    }));
+
+    frame.render_widget(List::new(items).style(theme.base()), area);
+}
 // Area where user inputs items
 fn draw_side_input(frame: &mut Frame, area: Rect, app: &mut App) {
-    let prompt = Span::styled(
-        "> ",
-        Style::default().add_modifier(Modifier::BOLD),
-    );
+    let theme = app.theme.theme();
+    let prompt = Span::styled("> ", theme.bright());
 
-    let input_line = Line::from(vec![prompt, Span::raw(&app.input)]);
+    let mut spans = vec![prompt, Span::raw(&app.input)];
+    if app.focus == Focus::Side && cursor_visible(app.tick, app.animations) {
+        spans.push(Span::styled("█", theme.bright()));
+    }
+    let input_line = Line::from(spans);
 
-    let input = Paragraph::new(input_line);
+    let input = Paragraph::new(input_line).style(theme.base());
     frame.render_widget(input, area);
 }
+// Helper: panel border, bright with an [active] tag when focused
+fn panel_block<'a>(title: &'a str, focused: bool, theme: &Theme) -> Block<'a> {
+    let title = if focused { format!("{title} [active]") } else { title.to_string() };
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.border(focused))
+}
+// Helper: words in a play cell are drawn bright, junk normal, spent dots and
+// ruled out words (`dimmed`, one flag per char) dim
+fn play_cell_line<'a>(val: &'a str, dimmed: &[bool], theme: &Theme) -> Line<'a> {
+    let spans: Vec<Span> = val.chars().enumerate().map(|(i, c)| {
+        let style = if dimmed.get(i).copied().unwrap_or(false) {
+            theme.dim()
+        } else if c.is_alphabetic() {
+            theme.bright()
+        } else if c == '.' {
+            theme.dim()
+        } else {
+            theme.base()
+        };
+        Span::styled(c.to_string(), style)
+    }).collect();
+    Line::from(spans)
+}
 // Helper: prevents history and input from drawing over border
 fn intersect(a: Rect, b: Rect) -> Rect {
     let x1 = a.x.max(b.x);
@@ -246,17 +526,15 @@ fn intersect(a: Rect, b: Rect) -> Rect {
 // ----------------------------------------------------------------------------
 
 
-fn draw_game_over (frame: &mut Frame, area: Rect) {
+fn draw_game_over (frame: &mut Frame, area: Rect, theme: &Theme) {
     // make a centered rect ~40% width, 30% height of the screen
     let popup_area = centered_rect(40, 30, area);
 
-    let block = Block::default()
-        .title(" Game Over ")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red));
+    let block = popup_block(" Game Over ", theme);
 
     let text = Paragraph::new("You lose!\nPress q to quit.")
         .alignment(Alignment::Center)
+        .style(theme.bright())
         .block(block);
 
     // Clear what's underneath so the box looks solid
@@ -265,17 +543,86 @@ fn draw_game_over (frame: &mut Frame, area: Rect) {
 }
 
 
-pub fn draw_help (frame: &mut Frame, area: Rect) {
+// a terminal run ended, what it got through instead of the plain Game Over
+fn draw_run_summary(frame: &mut Frame, area: Rect, run: &Run, best: u32, theme: &Theme) {
+    let popup_area = centered_rect(60, 60, area);
+    let block = popup_block(" Run Over ", theme);
+
+    let mut lines = vec![
+        Line::styled(format!("{} terminals cracked", run.cracked.len()), theme.bright()),
+        Line::from(format!("Score {}   best {best}", run.score)),
+        Line::from(format!("Guesses {}   brackets {}", run.guesses, run.brackets_used)),
+        Line::default(),
+    ];
+    for (i, cracked) in run.cracked.iter().enumerate() {
+        lines.push(Line::from(format!(
+            "{:>2}. {} x {} letters  {} left  +{}",
+            i + 1, cracked.word_count, cracked.word_len, cracked.attempts_left, cracked.points,
+        )));
+    }
+    lines.push(Line::default());
+    lines.push(Line::styled("Press q to quit.", theme.dim()));
+
+    let text = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .style(theme.bright())
+        .block(block);
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(text, popup_area);
+}
+
+fn draw_hints (frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
     let popup_area = centered_rect(40, 30, area);
+    let block = popup_block(" Hints ", theme);
 
-    let block = Block::default()
-        .title(" Help ")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red));
+    let mut lines: Vec<Line> = Vec::new();
+    if app.daily {
+        lines.push(Line::from("Hints are disabled on daily terminals."));
+    } else {
+        let left = HINT_BUDGET.saturating_sub(app.main.hints_used);
+        lines.push(Line::from(format!("{left} of {HINT_BUDGET} left, {HINT_COST} attempt each")));
+        lines.push(Line::default());
+        for hint in Hint::ALL {
+            lines.push(Line::from(format!("{}  {}", hint.key(), hint.label())));
+        }
+    }
+    lines.push(Line::default());
+    lines.push(Line::styled("any other key closes", theme.dim()));
+
+    let text = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .style(theme.base())
+        .block(block);
 
-    let text = Paragraph::new("To play the Fallout hacking minigame, you must guess the correct password from a list of words, all the same length, scattered among random characters. After each guess, the Likeness score will tell you how many letters in your word are also correct and in the right position. Use this score to eliminate other possibilities from the list. You can also find and click on matching bracket pairs, such as () or <>, to either remove a wrong dud password or reset your remaining attempts. You typically have four attempts to find the correct password. If you are down to your last try, you can exit and re-enter the terminal to reset the puzzle.")
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(text, popup_area);
+}
+
+
+pub fn draw_help (frame: &mut Frame, area: Rect, theme: &Theme) {
+    let popup_area = centered_rect(70, 90, area);
+
+    let block = popup_block(" Help ", theme);
+
+    // board keys first so they fit even when the rules below get cut off
+    let keys = [
+        ("hjkl", "move the cursor", "Enter", "guess, or use brackets"),
+        ("H", "hints, then 1-3", "c", "candidate tracker"),
+        ("t", "cycle theme", "a", "animations on/off"),
+        ("?", "close this help", "\\", "debug log"),
+        ("q", "quit", "", ""),
+    ];
+    let mut lines: Vec<Line> = keys
+        .iter()
+        .map(|(k1, what1, k2, what2)| Line::from(format!("{k1:<5} {what1:<18} {k2:<5} {what2}")))
+        .collect();
+    lines.push(Line::default());
+    lines.push(Line::from("To play the Fallout hacking minigame, you must guess the correct password from a list of words, all the same length, scattered among random characters. After each guess, the Likeness score will tell you how many letters in your word are also correct and in the right position. Use this score to eliminate other possibilities from the list. You can also find and click on matching bracket pairs, such as () or <>, to either remove a wrong dud password or reset your remaining attempts. You typically have four attempts to find the correct password. If you are down to your last try, you can exit and re-enter the terminal to reset the puzzle."));
+
+    let text = Paragraph::new(lines)
         .wrap(Wrap { trim: true})
-        .scroll((1, 0))
+        .style(theme.base())
         .block(block);
 
     // Clear what's underneath so the box looks solid
@@ -284,7 +631,7 @@ pub fn draw_help (frame: &mut Frame, area: Rect) {
 }
 
 
-pub fn draw_debug (frame: &mut Frame, area: Rect, debug: &DebugLog) {
+pub fn draw_debug (frame: &mut Frame, area: Rect, debug: &DebugLog, theme: &Theme) {
     let popup_area = centered_rect(40, 90, area);
 
     // Important for scrolling and starting at the bottom
@@ -306,16 +653,29 @@ pub fn draw_debug (frame: &mut Frame, area: Rect, debug: &DebugLog) {
         tail_iter.map(|line| ListItem::new(line.as_str()))
     );
 
-    let block = Block::default()
-        .title(" Debug ")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Red));
+    let block = popup_block(" Debug ", theme);
 
-    let list = List::new(visible).block(block);
+    let list = List::new(visible).style(theme.base()).block(block);
 
     frame.render_widget(Clear, popup_area);
     frame.render_widget(list, popup_area);
 }
+// status line over the bottom row of the screen, only while app.banner is set
+fn draw_banner(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let Some(banner) = &app.banner else { return; };
+    if area.height == 0 { return; }
+    let row = Rect { y: area.y + area.height - 1, height: 1, ..area };
+    frame.render_widget(Clear, row);
+    frame.render_widget(Paragraph::new(banner.as_str()).style(theme.highlight()), row);
+}
+// helper for popup frames
+fn popup_block<'a>(title: &'a str, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.popup_border())
+}
 // helper for draw_game_over()
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     // vertical split
@@ -340,3 +700,7 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 
     h[1]
 }
+
+
+#[cfg(test)]
+mod tests;
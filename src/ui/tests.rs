@@ -0,0 +1,136 @@
+// src/ui/tests.rs
+// Snapshot tests: render a seeded App on a TestBackend and compare the
+// buffer to the checked-in snapshots under src/ui/snapshots. After an
+// intended UI change, review and accept with `cargo insta review` (or rerun
+// with INSTA_UPDATE=always).
+
+use insta::assert_snapshot;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+use crate::app::{App, Difficulty, Screen};
+use crate::clock::{Countdown, LOW_MS};
+use crate::profile::{Perk, Profile};
+use crate::testing;
+
+const SEED: u64 = 45;
+
+fn draw(app: &mut App, width: u16, height: u16) -> TestBackend {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| super::render(f, app)).unwrap();
+    terminal.backend().clone()
+}
+
+fn board() -> App {
+    testing::board(SEED, Difficulty::Novice)
+}
+
+// a couple of wrong guesses so the side panel has history
+fn board_with_guesses() -> App {
+    let mut app = board();
+    for dud in testing::duds(&app.main).iter().take(2) {
+        assert!(testing::guess(&mut app, dud), "{dud} should be guessable");
+    }
+    app
+}
+
+
+#[test]
+fn board_standard_80x24() {
+    assert_snapshot!(draw(&mut board(), 80, 24));
+}
+
+#[test]
+fn board_wide_140x40() {
+    assert_snapshot!(draw(&mut board(), 140, 40));
+}
+
+#[test]
+fn board_compact_50x40() {
+    assert_snapshot!(draw(&mut board(), 50, 40));
+}
+
+#[test]
+fn board_too_small_40x12() {
+    assert_snapshot!(draw(&mut board(), 40, 12));
+}
+
+#[test]
+fn board_history_80x24() {
+    assert_snapshot!(draw(&mut board_with_guesses(), 80, 24));
+}
+
+#[test]
+fn help_80x24() {
+    let mut app = board();
+    app.show_help = true;
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn debug_80x24() {
+    let mut app = board_with_guesses();
+    app.show_debug = true;
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn game_over_80x24() {
+    let mut app = board();
+    for dud in testing::duds(&app.main) {
+        if app.game_over { break; }
+        testing::guess(&mut app, &dud);
+    }
+    assert!(app.game_over);
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn win_80x24() {
+    let mut app = board_with_guesses();
+    let password = app.main.password.clone();
+    assert!(testing::guess(&mut app, &password));
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn win_120x40() {
+    let mut app = board();
+    let password = app.main.password.clone();
+    assert!(testing::guess(&mut app, &password));
+    assert_snapshot!(draw(&mut app, 120, 40));
+}
+
+#[test]
+fn board_timed_low_80x24() {
+    let mut app = board();
+    app.header.clock = Some(Countdown { limit_ms: 60_000, left_ms: LOW_MS + 1 });
+    app.on_tick();
+    assert_eq!(app.header.status, app.content.status.time_low);
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn run_over_80x24() {
+    let mut app = testing::app(SEED);
+    app.toggle_animations();
+    assert!(app.start_run());
+    for _ in 0..2 {
+        let password = app.main.password.clone();
+        assert!(testing::guess(&mut app, &password));
+    }
+    for dud in testing::duds(&app.main) {
+        if app.game_over { break; }
+        testing::guess(&mut app, &dud);
+    }
+    assert!(app.game_over);
+    assert_snapshot!(draw(&mut app, 80, 24));
+}
+
+#[test]
+fn profile_80x24() {
+    let mut app = testing::app(SEED);
+    app.profile = Profile { science: 55, perks: vec![Perk::MoreBrackets] };
+    app.screen = Screen::Profile;
+    assert_snapshot!(draw(&mut app, 80, 24));
+}